@@ -2,6 +2,8 @@ use std::{ops::{Index, Range}, collections::HashMap};
 
 use sdl2::{image::LoadTexture, pixels::PixelFormatEnum, surface::Surface};
 
+use glider::{Fork, SHEET_HEIGHT, SHEET_WIDTH};
+
 use crate::{room, space::Rect};
 
 const ITEMS: [Rect; 100] = [
@@ -220,7 +222,8 @@ pub const BITS      : usize = 0;
 
 pub struct Atlas<P> {
     pixels: P,
-    blocks: HashMap<&'static str, &'static [Rect]>
+    blocks: HashMap<&'static str, &'static [Rect]>,
+    mono: bool,
 }
 
 impl<P> AsRef<P> for Atlas<P> {
@@ -236,9 +239,16 @@ impl<P> Atlas<P> {
     pub fn get(&self, name: &str) -> (&'static [Rect], &P) {
         (self.blocks[name], &self.pixels)
     }
+
+    pub fn mono(&self) -> bool { self.mono }
+
+    // The same sprites laid over other pixels, such as a texture made from this atlas's surface.
+    pub fn over<Q>(&self, pixels: Q) -> Atlas<Q> {
+        Atlas{pixels, blocks: self.blocks.clone(), mono: self.mono}
+    }
 }
 
-pub fn glider_sprites<P>(pixels: P) -> Atlas<P> {
+pub fn glider_sprites<P>(pixels: P, mono: bool) -> Atlas<P> {
     let blocks = HashMap::from_iter(
         [
             ("glider.right", &ITEMS[GLIDE_RIGHT]),
@@ -268,10 +278,26 @@ pub fn glider_sprites<P>(pixels: P) -> Atlas<P> {
     Atlas {
         pixels,
         blocks,
+        mono,
     }
 }
 
-pub fn rooms() -> HashMap<usize, Surface<'static>> {
+pub fn sprites(mono: bool, format: PixelFormatEnum) -> Result<Atlas<Surface<'static>>, String> {
+    let mut sprites = Surface::new(512, 598, format)?.into_canvas()?;
+    let creator = sprites.texture_creator();
+    let pixels = if mono {
+        let mut sheet = glider::sprite_sheet(&bw_art()?).map_err(|e| e.to_string())?;
+        let sheet = Surface::from_data(&mut sheet, SHEET_WIDTH as u32, SHEET_HEIGHT as u32, SHEET_WIDTH as u32 * 4, PixelFormatEnum::ABGR8888)?;
+        creator.create_texture_from_surface(sheet).map_err(|e| e.to_string())?
+    } else {
+        creator.load_texture_bytes(crate::resources::color::SPRITES)?
+    };
+    sprites.copy(&pixels, None, None).ok();
+    Ok(glider_sprites(sprites.into_surface(), mono))
+}
+
+pub fn rooms(mono: bool) -> HashMap<usize, Surface<'static>> {
+    if mono { return mono_rooms().unwrap_or_else(|e| { eprintln!("warning: B&W rooms unavailable ({e})"); HashMap::new() }) }
     HashMap::from_iter(
         crate::resources::color::assets().iter().filter_map(
             |(&index, &bytes)| 
@@ -287,3 +313,17 @@ pub fn rooms() -> HashMap<usize, Surface<'static>> {
         )
     )
 }
+
+// The B&W art is converted from the original resource file as it loads, rather than shipped pre-rendered.
+fn bw_art() -> Result<Fork, String> {
+    Fork::from_bytes(crate::resources::BW_ART).map_err(|e| e.to_string())
+}
+
+fn mono_rooms() -> Result<HashMap<usize, Surface<'static>>, String> {
+    let art = bw_art()?;
+    (200..210).map(|id| {
+        let mut bits = glider::picture(&art, id).map_err(|e| e.to_string())?.rgba();
+        let room = Surface::from_data(&mut bits, room::SCREEN_WIDTH, room::SCREEN_HEIGHT, room::SCREEN_WIDTH * 4, PixelFormatEnum::ABGR8888)?;
+        Ok((id as usize, room.convert_format(PixelFormatEnum::ABGR8888)?))
+    }).collect()
+}
@@ -0,0 +1,83 @@
+use std::{collections::HashMap, sync::Arc};
+
+use glider::{Cue, Sample};
+use sdl2::{AudioSubsystem, audio::{AudioCallback, AudioDevice, AudioSpecDesired}};
+use crate::resources;
+
+pub const CHANNELS: usize = 4;
+const MIX_RATE: i32 = 22254;
+
+struct Voice {
+    cue: Cue,
+    sample: Arc<Sample>,
+    at: f64,
+    step: f64,
+}
+
+impl Voice {
+    fn progress(&self) -> f64 { self.at / self.sample.data.len() as f64 }
+}
+
+struct Mixer {
+    voices: Vec<Option<Voice>>,
+    volume: f32,
+}
+
+impl AudioCallback for Mixer {
+    type Channel = f32;
+    fn callback(&mut self, out: &mut [f32]) {
+        for level in out.iter_mut() {
+            *level = 0.0;
+            for slot in self.voices.iter_mut() {
+                let Some(voice) = slot else { continue };
+                match voice.sample.level(voice.at as usize) {
+                    Some(sample) => {
+                        *level += sample / 2.0 * self.volume;
+                        voice.at += voice.step;
+                    }
+                    None => *slot = None,
+                }
+            }
+            *level = level.clamp(-1.0, 1.0);
+        }
+    }
+}
+
+pub struct Audio {
+    device: AudioDevice<Mixer>,
+    sounds: HashMap<i16, Arc<Sample>>,
+}
+
+impl Audio {
+    pub fn open(audio: &AudioSubsystem, channels: usize, volume: u8) -> Result<Self, String> {
+        let sounds = glider::rez_resources(resources::SOUNDS, "snd ")
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter_map(|(id, name, resource)| match Sample::decode(&resource) {
+                Ok(sample) => Some((id, Arc::new(sample))),
+                Err(e) => { eprintln!("warning: skipped sound \"{name}\" ({e})"); None }
+            })
+            .collect();
+        let desired = AudioSpecDesired{freq: Some(MIX_RATE), channels: Some(1), samples: Some(512)};
+        let device = audio.open_playback(None, &desired, |_| Mixer{voices: Vec::from_iter((0..channels.clamp(1, CHANNELS)).map(|_| None)), volume: volume.min(100) as f32 / 100.0})?;
+        device.resume();
+        Ok(Self{device, sounds})
+    }
+
+    pub fn play(&mut self, cue: Cue) {
+        let Some(sample) = self.sounds.get(&cue.resource()) else { return };
+        let step = sample.rate / self.device.spec().freq as f64;
+        let mut mixer = self.device.lock();
+        if mixer.voices.iter().flatten().any(|voice| voice.cue == cue) { return }
+        let slot = match mixer.voices.iter().position(Option::is_none) {
+            Some(free) => free,
+            None => mixer.voices.iter().enumerate()
+                .max_by(|(_, a), (_, b)| a.as_ref().map_or(0.0, Voice::progress).total_cmp(&b.as_ref().map_or(0.0, Voice::progress)))
+                .map(|(oldest, _)| oldest)
+                .unwrap_or_default(),
+        };
+        if let Some(voice) = mixer.voices.get_mut(slot) {
+            *voice = Some(Voice{cue, sample: sample.clone(), at: 0.0, step});
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::{error::Error, path::Path};
+
+use glider::House;
+
+fn is_json(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [from, to] = args.as_slice() else {
+        eprintln!("usage: glider-convert <house> <house.json>");
+        eprintln!("       glider-convert <house.json> <house>");
+        std::process::exit(2);
+    };
+    let (from, to) = (Path::new(from), Path::new(to));
+
+    let house = if is_json(from) {
+        House::from_json(&std::fs::read_to_string(from)?)?
+    } else {
+        House::open(from)?
+    };
+    if is_json(to) {
+        std::fs::write(to, house.to_json()? + "\n")?;
+    } else {
+        house.save(to)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::{collections::HashSet, error::Error, num::NonZero, path::{Path, PathBuf}};
+
+use glider::{House, ImportMode};
+
+fn houses_in(folder: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut paths = std::fs::read_dir(folder)?.map(|entry| entry.map(|e| e.path())).collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+    let houses = paths.into_iter().filter_map(|path| Some((House::open(&path).ok()?, path))).collect::<Vec<_>>();
+    let continued = houses.iter().map(|(house, _)| house.next_file.as_str()).filter(|name| !name.is_empty()).collect::<HashSet<_>>();
+    Ok(houses.iter()
+        .filter(|(_, path)| !path.file_name().is_some_and(|name| continued.contains(name.to_string_lossy().as_ref())))
+        .map(|(_, path)| path.clone())
+        .collect())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.is_empty() { args.push("Houses".into()) }
+    let start = const{ NonZero::new(1).unwrap() };
+    let mut flawed = false;
+    for arg in args {
+        let path = Path::new(&arg);
+        let paths = if path.is_dir() { houses_in(path)? } else { vec![path.to_path_buf()] };
+        for path in paths {
+            let name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().to_string();
+            let (house, parts) = match House::open_set(&path, ImportMode::Lenient) {
+                Ok(set) => set,
+                Err(error) => { println!("{name}: {error}"); flawed = true; continue }
+            };
+            let issues = parts.iter().flat_map(|part| part.report.iter().map(|issue| format!("skipped {issue}")));
+            let lints = house.lint(start).into_iter().map(|lint| lint.to_string());
+            for problem in issues.chain(lints) {
+                println!("{name}: {problem}");
+                flawed = true;
+            }
+        }
+    }
+    if flawed { std::process::exit(1) }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use std::error::Error;
+
+use glider::{House, ImportMode, Replay};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [house, replay] = args.as_slice() else {
+        eprintln!("usage: glider-replay <house> <replay>");
+        std::process::exit(2);
+    };
+    let (house, _) = House::open_set(house, ImportMode::Lenient)?;
+    let replay = Replay::open(replay)?;
+    let ending = replay.run(&house)?;
+    println!("seed {}, {} frames", replay.seed, replay.frames().count());
+    println!("room {}, glider at {:?}", ending.room, ending.player);
+    println!("score {}, gliders {}, bands {}, energy {}", ending.status.score, ending.status.mortals, ending.status.bands, ending.status.energy);
+    println!("checksum {:016x}", ending.checksum);
+    match replay.checksum {
+        Some(expected) if expected != ending.checksum => {
+            println!("recorded checksum {expected:016x} differs");
+            std::process::exit(1);
+        }
+        Some(_) => println!("matches recording"),
+        None => println!("recording has no checksum"),
+    }
+    Ok(())
+}
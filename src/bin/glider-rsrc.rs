@@ -0,0 +1,41 @@
+use std::{error::Error, path::Path};
+
+use glider::Fork;
+
+const USAGE: &str = "usage: glider-rsrc <file.bin> [--type=TYPE] [--id=N] [--extract=folder]";
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    std::process::exit(2)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let flag = |name: &str| args.iter().find_map(|arg| arg.strip_prefix(name)?.strip_prefix('='));
+    let [path] = args.iter().filter(|arg| !arg.starts_with("--")).collect::<Vec<_>>()[..] else { usage() };
+    let id = flag("--id").map(|id| id.parse::<i16>().unwrap_or_else(|_| usage()));
+    let fork = Fork::open(path).map_err(|e| format!("Couldn't read \"{path}\": {e}"))?;
+
+    let mut chosen = fork.resources.iter()
+        .filter(|resource| flag("--type").is_none_or(|kind| resource.kind.trim_end() == kind.trim_end()))
+        .filter(|resource| id.is_none_or(|id| resource.id == id))
+        .collect::<Vec<_>>();
+    chosen.sort_by_key(|resource| (&resource.kind, resource.id));
+    match flag("--extract") {
+        Some(folder) => {
+            std::fs::create_dir_all(folder)?;
+            for resource in &chosen {
+                let file = Path::new(folder).join(format!("{}_{}.bin", resource.kind.trim_end(), resource.id));
+                std::fs::write(&file, &resource.data)?;
+                println!("{}", file.display());
+            }
+        }
+        None => {
+            println!("{} (type {:?}, creator {:?}), {} data bytes, {} resources of {} types", fork.name, fork.file_type, fork.creator, fork.data.len(), fork.resources.len(), fork.kinds().len());
+            for resource in &chosen {
+                println!("{:<4} {:>6} {:>8}  {}", resource.kind, resource.id, resource.data.len(), resource.name.as_deref().unwrap_or_default());
+            }
+        }
+    }
+    Ok(())
+}
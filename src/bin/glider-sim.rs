@@ -0,0 +1,76 @@
+use std::{error::Error, num::NonZero};
+
+use glider::{Entrance, House, ImportMode, Input, Mark, Replay, Side};
+
+const USAGE: &str = "usage: glider-sim <house> [--room=N] [--entrance=spawn-left|spawn-right|flying-left:Y|flying-right:Y|up|down|air]
+                  [--seed=N] [--bands=N] [--frames=N] [--script=inputs.json | --replay=file]";
+
+fn entrance(text: &str) -> Option<Entrance> {
+    let (name, height) = text.split_once(':').map_or((text, None), |(name, height)| (name, height.parse().ok()));
+    Some(match (name, height) {
+        ("spawn-left", None) => Entrance::Spawn(Side::Left),
+        ("spawn-right", None) => Entrance::Spawn(Side::Right),
+        ("flying-left", Some(y)) => Entrance::Flying(Side::Left, y),
+        ("flying-right", Some(y)) => Entrance::Flying(Side::Right, y),
+        ("up", None) => Entrance::Up,
+        ("down", None) => Entrance::Down,
+        ("air", None) => Entrance::Air,
+        _ => return None,
+    })
+}
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    std::process::exit(2);
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let flag = |name: &str| args.iter().find_map(|arg| arg.strip_prefix(name)?.strip_prefix('='));
+    let [house] = args.iter().filter(|arg| !arg.starts_with("--")).collect::<Vec<_>>()[..] else { usage() };
+    let (house, _) = House::open_set(house, ImportMode::Lenient)?;
+
+    let replay = flag("--replay").map(Replay::open).transpose()?;
+    let entered = replay.iter().flat_map(|replay| &replay.marks).find_map(|mark| match mark {
+        Mark::Enter(room, at) => Some((*room, *at)),
+        Mark::Frames(..) => None,
+    });
+    let room = match flag("--room") {
+        Some(room) => room.parse::<NonZero<u16>>().unwrap_or_else(|_| usage()),
+        None => entered.map_or(const{ NonZero::new(1).unwrap() }, |(room, _)| room),
+    };
+    let at = match flag("--entrance") {
+        Some(at) => entrance(at).unwrap_or_else(|| usage()),
+        None => entered.map_or(Entrance::default(), |(_, at)| at),
+    };
+    let seed = match flag("--seed") {
+        Some(seed) => seed.parse().unwrap_or_else(|_| usage()),
+        None => replay.as_ref().map_or(0, |replay| replay.seed.wrapping_add(room.get() as u64 - 1)),
+    };
+    let bands = flag("--bands").map_or(0, |bands| bands.parse().unwrap_or_else(|_| usage()));
+    let script: Vec<Vec<Input>> = match (flag("--script"), &replay) {
+        (Some(path), _) => serde_json::from_str(&std::fs::read_to_string(path)?)?,
+        (None, Some(replay)) => replay.marks.iter().skip_while(|mark| !matches!(mark, Mark::Enter(..))).skip(1).map_while(|mark| match mark {
+            Mark::Frames(count, inputs) => Some(std::iter::repeat_n(inputs.clone(), *count as usize)),
+            Mark::Enter(..) => None,
+        }).flatten().collect(),
+        (None, None) => Vec::new(),
+    };
+    let frames = match flag("--frames") {
+        Some(frames) => frames.parse().unwrap_or_else(|_| usage()),
+        None => script.len().max(1) as u32,
+    };
+
+    let Some(chosen) = house.get(room.get() as usize - 1) else {
+        return Err(format!("house has no room {room}").into())
+    };
+    let steps = glider::simulate(chosen, at, seed, bands, frames, script.iter().map(Vec::as_slice));
+    println!("{}", serde_json::to_string_pretty(&serde_json::json!({
+        "room": room,
+        "name": chosen.name,
+        "entrance": at,
+        "seed": seed,
+        "steps": steps,
+    }))?);
+    Ok(())
+}
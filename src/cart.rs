@@ -8,8 +8,11 @@ use crate::Side;
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Displacement {
+    #[cfg_attr(feature = "serde", serde(rename = "x"))]
     x_: i16,
+    #[cfg_attr(feature = "serde", serde(rename = "y"))]
     y_: i16,
 }
 
@@ -157,6 +160,8 @@ where
 
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Point (Displacement);
 
 impl Debug for Point {
@@ -272,8 +277,11 @@ impl<N: Div<Output = N>, I: Into<Point<N>>> Div<I> for Point<N> where Self: DivA
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
+    #[cfg_attr(feature = "serde", serde(rename = "width"))]
     width_: NonZero<u16>,
+    #[cfg_attr(feature = "serde", serde(rename = "height"))]
     height_: NonZero<u16>,
 }
 
@@ -0,0 +1,66 @@
+use std::time::{Duration, Instant};
+
+// The original paced play off TickCount, two ticks of 1/60 s per frame.
+pub const TICK: Duration = Duration::from_micros(33_333);
+const CATCH_UP: u32 = 4;
+
+pub struct Clock {
+    tick: Duration,
+    due: Instant,
+    dropped: u32,
+}
+
+impl Clock {
+    pub fn new(speed: u32, now: Instant) -> Self {
+        Self{tick: TICK * 100 / speed.max(1), due: now, dropped: 0}
+    }
+
+    pub fn sleep(&self) {
+        if let Some(wait) = self.due.checked_duration_since(Instant::now()) { std::thread::sleep(wait) }
+    }
+
+    pub fn hold(&mut self, now: Instant) { self.due = now + self.tick }
+
+    // How many frames of play are due by now. Falling further behind than CATCH_UP frames skips the rest and counts them as dropped,
+    // to be reported once when the clock goes rather than on every stall.
+    pub fn ticks(&mut self, now: Instant) -> u32 {
+        let mut ticks = 0;
+        while self.due <= now {
+            self.due += self.tick;
+            ticks += 1;
+        }
+        if ticks > CATCH_UP {
+            self.dropped += ticks - CATCH_UP;
+            ticks = CATCH_UP;
+        }
+        ticks
+    }
+}
+
+impl Drop for Clock {
+    fn drop(&mut self) {
+        if self.dropped > 0 { eprintln!("warning: play fell behind and dropped {} frames", self.dropped) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fixed_steps() {
+        let start = Instant::now();
+        let mut clock = Clock::new(100, start);
+        assert_eq!(clock.ticks(start), 1);
+        assert_eq!(clock.ticks(start + TICK / 2), 0);
+        assert_eq!(clock.ticks(start + TICK * 2), 2);
+        assert_eq!(clock.ticks(start + TICK * 20), CATCH_UP);
+        assert_eq!(clock.dropped, 14);
+        clock.hold(start + TICK * 30);
+        assert_eq!(clock.ticks(start + TICK * 30), 0);
+
+        let mut slow = Clock::new(50, start);
+        let mut fast = Clock::new(200, start);
+        assert_eq!((slow.ticks(start + TICK * 3), fast.ticks(start + TICK * 2)), (2, CATCH_UP));
+    }
+}
@@ -4,17 +4,7 @@ use glider::{prelude::*, Bounds, Reference};
 use sdl2::{pixels::{Color, PixelFormatEnum}, rect::{Point, Rect}, render::{BlendMode, Canvas, RenderTarget, Texture, TextureCreator}, surface::Surface, video::Window};
 use crate::{room::{SCREEN_HEIGHT, SCREEN_WIDTH, VERT_FLOOR}, space, atlas::{self, Atlas}, resources};
 
-fn random() -> u16 {
-	use std::sync::LazyLock;
-	use random::Source;
-	static mut RAND: LazyLock<std::cell::RefCell<random::Default>> = LazyLock::new(|| std::cell::RefCell::new(random::default(
-		match std::time::SystemTime::UNIX_EPOCH.elapsed() {
-			Ok(length) => length,
-			Err(wrong) => wrong.duration(),
-		}.as_secs()
-	)));
-	unsafe { RAND.borrow_mut().read::<u16>() }
-}
+pub use hud::{age_popups, float_the_score, Banner, Board, Popups};
 
 pub type Frame = Box<dyn Iterator<Item = usize>>;
 pub type Animations = RefCell<HashMap<usize, Frame>>;
@@ -31,6 +21,63 @@ const GRAY_DK   : Color = Color::RGB(0x80, 0x80, 0x80);
 const BLUE      : Color = Color::RGB(0x00, 0x00, 0xFF);
 const GREEN_LT  : Color = Color::RGB(0x1F, 0xB8, 0x14);
 
+// QuickDraw's stock patterns: a byte per row of eight pixels, set bits inked black, tiled from the top left of the screen.
+const LT_GRAY   : [u8; 8] = [0x88, 0x22, 0x88, 0x22, 0x88, 0x22, 0x88, 0x22];
+const GRAY      : [u8; 8] = [0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55];
+const DK_GRAY   : [u8; 8] = [0x77, 0xDD, 0x77, 0xDD, 0x77, 0xDD, 0x77, 0xDD];
+
+#[derive(Clone, Copy)]
+enum Ink {
+    Solid(Color),
+    Pattern(Option<Color>, [u8; 8]),
+}
+
+// The B&W palette. Woods and shade become patterns on white as in the original's 1-bit furniture, overlays stipple
+// whatever is under them, and every other colour is black or white by brightness, which turns the HUD's yellow white.
+fn ink(tone: Color, mono: bool) -> Ink {
+    match tone {
+        _ if !mono => Ink::Solid(tone),
+        BROWN => Ink::Pattern(Some(WHITE), DK_GRAY),
+        BROWN_LT => Ink::Pattern(Some(WHITE), LT_GRAY),
+        GRAY_DK => Ink::Pattern(Some(WHITE), GRAY),
+        Color{a: 255, r, g, b} if r as u32 * 299 + g as u32 * 587 + b as u32 * 114 >= 128 * 1000 => Ink::Solid(WHITE),
+        Color{a: 255, ..} => Ink::Solid(BLACK),
+        _ => Ink::Pattern(None, GRAY),
+    }
+}
+
+fn paint<R: RenderTarget>(canvas: &mut Canvas<R>, ink: Ink, bounds: Rect) -> Result<(), String> {
+    match ink {
+        Ink::Solid(tone) => {
+            canvas.set_draw_color(tone);
+            canvas.fill_rect(bounds)
+        }
+        Ink::Pattern(ground, pattern) => {
+            if let Some(ground) = ground {
+                canvas.set_draw_color(ground);
+                canvas.fill_rect(bounds)?;
+            }
+            canvas.set_draw_color(BLACK);
+            canvas.draw_points((bounds.top()..bounds.bottom())
+                .flat_map(|y| (bounds.left()..bounds.right()).map(move |x| Point::new(x, y)))
+                .filter(|point| pattern[point.y().rem_euclid(8) as usize] & 0x80 >> point.x().rem_euclid(8) != 0)
+                .collect::<Vec<_>>().as_slice())
+        }
+    }
+}
+
+// Patterns tile along a stroke as they do in a fill, a segment at a time; the furniture is all level and plumb lines.
+fn stroke<R: RenderTarget>(canvas: &mut Canvas<R>, ink: Ink, vertices: &[Point]) -> Result<(), String> {
+    match ink {
+        Ink::Solid(tone) => {
+            canvas.set_draw_color(tone);
+            canvas.draw_lines(vertices)
+        }
+        ink @ Ink::Pattern(..) => vertices.windows(2).filter_map(|ends| Rect::from_enclose_points(ends, None))
+            .try_for_each(|segment| paint(canvas, ink, segment)),
+    }
+}
+
 pub trait Visible {
     fn show<Display: Scribe>(&self, display: &mut Display);
 }
@@ -143,6 +190,11 @@ mod object {
                 Is::Balloon(..) => ("balloon", atlas::POPPED, CENTER),
                 Is::Copter(..) => ("copter", atlas::CRUMPLED, CENTER),
                 Is::Dart(..) => ("dart", atlas::CRUSHED, CENTER),
+                Is::Downed(ref enemy) => return {
+                    let (category, frame) = match **enemy { Is::Balloon(..) => ("balloon", atlas::POPPED), Is::Copter(..) => ("copter", atlas::CRUMPLED), _ => ("dart", atlas::CRUSHED) };
+                    display.sprite(space::Point::from(self.1.position).into(), CENTER, category, frame);
+                },
+                Is::Band(..) => ("band", atlas::SHOT.start + (self.1.position.x() / 16).rem_euclid(atlas::SHOT.len() as i16) as usize, CENTER),
                 Is::Stair(direction, ..) => ("stairs", match direction {Vertical::Up => atlas::STAIRS_UP, Vertical::Down => atlas::STAIRS_DOWN}, BOTTOM),
                 #[cfg(debug_assertions)]
                 _ => return eprintln!("Object {:?} NOT IMPLEMENTED yet.", self.1)
@@ -304,11 +356,12 @@ mod object {
 
     mod shadow {
         use super::super::*;
+        // Shadows are the same in colour and B&W: solid black with the gray pattern knocked out of it.
         fn descreen(target: &mut Surface, width: u32) {
             target.without_lock_mut().map(|pixels| {
                 for (v, row) in pixels.as_chunks_mut::<4>().0.chunks_mut(width as usize).enumerate() {
-                    for pair in row[(v % 2)..].chunks_mut(2) {
-                        pair[0] = [0x00; 4];
+                    for (h, pixel) in row.iter_mut().enumerate() {
+                        if GRAY[v % 8] & 0x80 >> (h % 8) != 0 { *pixel = [0x00; 4] }
                     }
                 }
             });
@@ -494,7 +547,109 @@ mod room {
                 },
                 None => display.sprite((player_position.0, player_position.1 + 10), BOTTOM, block, frame),
             } 
-            display.publish();
+        }
+    }
+}
+
+mod hud {
+    use glider::{Status, Success};
+    use crate::{font, room::VERT_CEILING};
+
+    use super::*;
+
+    const FLOAT_FRAMES: u8 = 24;
+    const BAR_HEIGHT: u32 = VERT_CEILING - 8;
+
+    pub struct Popup {
+        points: u16,
+        position: Position,
+        age: u8,
+    }
+
+    pub type Popups = RefCell<Vec<Popup>>;
+
+    pub struct Banner<'a>(pub &'a str, pub &'a str);
+
+    pub struct Board<'a>(pub &'a str, pub &'a [Success], pub Option<usize>);
+
+    const ROW_HEIGHT: i32 = 14;
+    const ROOM_CHARS: usize = 30;
+
+    pub fn float_the_score(popups: &Popups, points: u16, position: Position) {
+        popups.borrow_mut().push(Popup{points, position, age: 0})
+    }
+
+    // Popups rise a pixel a frame of play, however often they're drawn.
+    pub fn age_popups(popups: &Popups) {
+        popups.borrow_mut().retain_mut(|popup| { popup.age += 1; popup.age <= FLOAT_FRAMES })
+    }
+
+    // Yellow marks the text that matters; in B&W, where it would be just more white, that text is shown in inverse video instead.
+    fn shadowed<Display: Scribe>(display: &mut Display, (left, top): (i32, i32), text: &str, tone: Color) -> Result<(), String> {
+        if tone == YELLOW && display.mono() {
+            display.fill(WHITE, Rect::new(left - 2, top - 2, font::width(text) as u32 + 4, font::HEIGHT as u32 + 4))?;
+            return font::write(display, (left, top), text, BLACK)
+        }
+        font::write(display, (left + 1, top + 1), text, BLACK)?;
+        font::write(display, (left, top), text, tone)
+    }
+
+    impl Visible for Popups {
+        fn show<Display: Scribe>(&self, display: &mut Display) {
+            for popup in self.borrow().iter() {
+                let text = popup.points.to_string();
+                let left = popup.position.x() as i32 - font::width(&text) / 2;
+                let top = popup.position.y() as i32 - font::HEIGHT - popup.age as i32;
+                if let Err(e) = shadowed(display, (left, top), &text, YELLOW) { eprintln!("{e}") }
+            }
+        }
+    }
+
+    impl Visible for Banner<'_> {
+        fn show<Display: Scribe>(&self, display: &mut Display) {
+            let &Banner(title, hint) = self;
+            let middle = SCREEN_HEIGHT as i32 / 2;
+            let centered = |text: &str| (SCREEN_WIDTH as i32 - font::width(text)) / 2;
+            if let Err(e) = display.fill((0, 0, 0, 120), Rect::new(0, BAR_HEIGHT as i32, SCREEN_WIDTH, SCREEN_HEIGHT - BAR_HEIGHT))
+                .and_then(|_| shadowed(display, (centered(title), middle - font::HEIGHT - 4), title, YELLOW))
+                .and_then(|_| shadowed(display, (centered(hint), middle + 4), hint, WHITE)) {
+                    eprintln!("{e}")
+            }
+        }
+    }
+
+    impl Visible for Board<'_> {
+        fn show<Display: Scribe>(&self, display: &mut Display) {
+            let &Board(title, scores, highlight) = self;
+            let title = format!("{title} HIGH SCORES");
+            let mut lines = vec![shadowed(display, ((SCREEN_WIDTH as i32 - font::width(&title)) / 2, 24), &title, YELLOW)];
+            for (rank, success) in scores.iter().enumerate().filter(|(_, success)| success.score > 0) {
+                let top = 52 + rank as i32 * ROW_HEIGHT;
+                let tone = if highlight == Some(rank) { YELLOW } else { WHITE };
+                let (place, points) = (format!("{}.", rank + 1), success.score.to_string());
+                let room = success.room.chars().take(ROOM_CHARS).collect::<String>();
+                lines.extend([
+                    shadowed(display, (52 - font::width(&place), top), &place, tone),
+                    shadowed(display, (64, top), &success.name, tone),
+                    shadowed(display, (292 - font::width(&points), top), &points, tone),
+                    shadowed(display, (312, top), &room, tone),
+                ]);
+            }
+            for e in lines.into_iter().filter_map(Result::err) { eprintln!("{e}") }
+        }
+    }
+
+    impl Visible for (&Status, &str) {
+        fn show<Display: Scribe>(&self, display: &mut Display) {
+            let &(status, name) = self;
+            let top = (BAR_HEIGHT as i32 - font::HEIGHT) / 2;
+            let counters = format!("GLIDERS {} BANDS {} ENERGY {}", status.spares(), status.bands, status.energy);
+            if let Err(e) = display.fill((0, 0, 0, 160), Rect::new(0, 0, SCREEN_WIDTH, BAR_HEIGHT))
+                .and_then(|_| shadowed(display, (8, top), &format!("SCORE {}", status.score), WHITE))
+                .and_then(|_| shadowed(display, ((SCREEN_WIDTH as i32 - font::width(name)) / 2, top), name, YELLOW))
+                .and_then(|_| shadowed(display, (SCREEN_WIDTH as i32 - 8 - font::width(&counters), top), &counters, WHITE)) {
+                    eprintln!("{e}")
+            }
         }
     }
 }
@@ -542,13 +697,19 @@ pub trait Scribe : Illuminator {
     fn sink_rect(&mut self, bounds: Rect, fill: impl Into<Option<Color>>) -> Result<(), String>;
     fn show<V: Visible>(&mut self, item: &V) -> &mut Self;
     fn sprite(&mut self, position: (i16, i16), anchor: Anchor, name: &str, index: usize);
+    fn mono(&self) -> bool;
 }
 
 impl<R:RenderTarget> Scribe for (&mut Canvas<R>, &Atlas<Texture<'_>>) where Self: Illuminator {
     fn clear(&mut self, color: sdl2::pixels::Color) {
-        let display = &mut *self.0;
-        display.set_draw_color(color);
-        display.clear();
+        let (width, height) = self.0.output_size().unwrap_or((SCREEN_WIDTH, SCREEN_HEIGHT));
+        match ink(color, self.mono()) {
+            Ink::Solid(tone) => {
+                self.0.set_draw_color(tone);
+                self.0.clear();
+            }
+            ink => paint(self.0, ink, Rect::new(0, 0, width, height)).unwrap_or_else(|e| eprintln!("{e}")),
+        }
     }
     fn publish(&mut self) { self.0.present() }
 
@@ -563,22 +724,17 @@ impl<R:RenderTarget> Scribe for (&mut Canvas<R>, &Atlas<Texture<'_>>) where Self
             .expect("failed to draw to canvas");
     }
 
-    fn pen<const N: usize>(&mut self, stroke: impl Into<Color>, vertices: &[(i32, i32); N]) -> Result<(), String> {
-        let display = &mut *self.0;
-        display.set_draw_color(stroke.into());
-        display.draw_lines(vertices.map(|(x, y)| sdl2::rect::Point::new(x, y) ).as_ref())
+    fn pen<const N: usize>(&mut self, tone: impl Into<Color>, vertices: &[(i32, i32); N]) -> Result<(), String> {
+        stroke(self.0, ink(tone.into(), self.mono()), vertices.map(|(x, y)| sdl2::rect::Point::new(x, y) ).as_ref())
     }
 
     fn fill(&mut self, tone: impl Into<Color>, bounds: Rect) -> Result<(), String> {
-        let display = &mut *self.0;
-        display.set_draw_color(tone.into());
-        display.fill_rect(bounds)
+        paint(self.0, ink(tone.into(), self.mono()), bounds)
     }
 
     fn outline_rect(&mut self, bounds: Rect, fill: impl Into<Color>) -> Result<(), String> {
+        self.fill(fill, bounds)?;
         let display = &mut *self.0;
-        display.set_draw_color(fill.into());
-        display.fill_rect(bounds)?;
         // display.set_blend_mode(BlendMode::Blend);
         display.set_draw_color(BLACK);
         display.draw_rect(bounds)
@@ -586,9 +742,7 @@ impl<R:RenderTarget> Scribe for (&mut Canvas<R>, &Atlas<Texture<'_>>) where Self
 
     fn limn_rect(&mut self, bounds: Rect, fill: impl Into<Color>, hilite: impl Into<Color>) -> Result<(), String> {
         self.outline_rect(bounds, fill)?;
-        let display = &mut *self.0;
-        display.set_draw_color(hilite);
-        display.draw_lines([
+        stroke(self.0, ink(hilite.into(), self.mono()), [
             Point::new(bounds.left() + 1, bounds.top() +1),
             Point::new(bounds.right() - 2, bounds.top() + 1),
             Point::new(bounds.right() - 2, bounds.bottom() - 2),
@@ -599,15 +753,12 @@ impl<R:RenderTarget> Scribe for (&mut Canvas<R>, &Atlas<Texture<'_>>) where Self
         if let Some(fill) = fill.into() {
             self.outline_rect(bounds, fill)?;
         }
-        let display = &mut *self.0;
-        display.set_draw_color(BROWN_LT);
-        display.draw_lines([
+        stroke(self.0, ink(BROWN_LT, self.mono()), [
             Point::new(bounds.left() - 1, bounds.top()),
             Point::new(bounds.left() - 1, bounds.bottom()),
             Point::new(bounds.right() - 1, bounds.bottom()),
         ].as_ref())?;
-        display.set_draw_color(GRAY_DK);
-        display.draw_lines([
+        stroke(self.0, ink(GRAY_DK, self.mono()), [
             Point::new(bounds.left(), bounds.top() - 1),
             Point::new(bounds.right(), bounds.top() - 1),
             Point::new(bounds.right(), bounds.bottom()),
@@ -623,4 +774,6 @@ impl<R:RenderTarget> Scribe for (&mut Canvas<R>, &Atlas<Texture<'_>>) where Self
         let bounds = space::Rect::from(size / anchor << *Reference::from(position));
         self.draw(tex, wedge[index], bounds);
     }
+
+    fn mono(&self) -> bool { self.1.mono() }
 }
@@ -0,0 +1,162 @@
+use std::{fmt::Display, path::Path};
+
+use super::import::mac_roman;
+
+const HEADER: usize = 128;
+const NO_NAME: u16 = 0xFFFF;
+
+#[disclose]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resource {
+    kind: String,
+    id: i16,
+    name: Option<String>,
+    data: Vec<u8>,
+}
+
+#[disclose]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fork {
+    name: String,
+    file_type: String,
+    creator: String,
+    data: Vec<u8>,
+    resources: Vec<Resource>,
+}
+
+#[derive(Debug)]
+pub enum ForkError {
+    NotMacBinary,
+    Truncated,
+    BadMap(&'static str),
+    Unreadable(std::io::Error),
+}
+
+impl Display for ForkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotMacBinary => write!(f, "file isn't MacBinary"),
+            Self::Truncated => write!(f, "file ends before its resource fork does"),
+            Self::BadMap(what) => write!(f, "resource map is damaged ({what})"),
+            Self::Unreadable(err) => write!(f, "file could not be read ({err})"),
+        }
+    }
+}
+
+impl std::error::Error for ForkError {}
+
+impl From<std::io::Error> for ForkError {
+    fn from(value: std::io::Error) -> Self { Self::Unreadable(value) }
+}
+
+fn word(bytes: &[u8], at: usize) -> Result<u16, ForkError> {
+    Ok(u16::from_be_bytes(*bytes.get(at..).and_then(|rest| rest.first_chunk()).ok_or(ForkError::BadMap("offset past the end"))?))
+}
+
+fn long(bytes: &[u8], at: usize) -> Result<u32, ForkError> {
+    Ok(u32::from_be_bytes(*bytes.get(at..).and_then(|rest| rest.first_chunk()).ok_or(ForkError::BadMap("offset past the end"))?))
+}
+
+// Inside Macintosh: More Macintosh Toolbox, 1-121: a 16-byte header, the data, then a map of type list, reference lists and names.
+fn resources(fork: &[u8]) -> Result<Vec<Resource>, ForkError> {
+    if fork.is_empty() { return Ok(Vec::new()) }
+    let (data_at, map_at) = (long(fork, 0)? as usize, long(fork, 4)? as usize);
+    let map = fork.get(map_at..).ok_or(ForkError::BadMap("map past the end"))?;
+    let (types_at, names_at) = (word(map, 24)? as usize, word(map, 26)? as usize);
+    let types = map.get(types_at..).ok_or(ForkError::BadMap("type list past the end"))?;
+    let mut found = Vec::new();
+    for n in 0..word(types, 0)?.wrapping_add(1) as usize {
+        let entry = 2 + n * 8;
+        let kind = mac_roman(types.get(entry..entry + 4).ok_or(ForkError::BadMap("type list past the end"))?);
+        let (count, refs_at) = (word(types, entry + 4)? as usize + 1, word(types, entry + 6)? as usize);
+        for r in 0..count {
+            let at = refs_at + r * 12;
+            let id = word(types, at)? as i16;
+            let name = match word(types, at + 2)? {
+                NO_NAME => None,
+                offset => {
+                    let names = map.get(names_at + offset as usize..).ok_or(ForkError::BadMap("name past the end"))?;
+                    let &len = names.first().ok_or(ForkError::BadMap("name past the end"))?;
+                    Some(mac_roman(names.get(1..=len as usize).ok_or(ForkError::BadMap("name past the end"))?))
+                }
+            };
+            let start = data_at + (long(types, at + 4)? & 0x00FF_FFFF) as usize;
+            let len = long(fork, start)? as usize;
+            let data = fork.get(start + 4..start + 4 + len).ok_or(ForkError::BadMap("resource past the end"))?.to_vec();
+            found.push(Resource{kind: kind.clone(), id, name, data});
+        }
+    }
+    Ok(found)
+}
+
+fn padded(len: usize) -> usize { len.div_ceil(HEADER) * HEADER }
+
+impl Fork {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ForkError> {
+        let header = bytes.first_chunk::<HEADER>().ok_or(ForkError::NotMacBinary)?;
+        let name_len = header[1] as usize;
+        if header[0] != 0 || header[74] != 0 || header[82] != 0 || !(1..=63).contains(&name_len) { return Err(ForkError::NotMacBinary) }
+        let (data_len, fork_len) = (long(header, 83)? as usize, long(header, 87)? as usize);
+        let data = bytes.get(HEADER..HEADER + data_len).ok_or(ForkError::Truncated)?.to_vec();
+        let fork_at = HEADER + padded(data_len);
+        let fork = bytes.get(fork_at..fork_at + fork_len).ok_or(ForkError::Truncated)?;
+        Ok(Self{
+            name: mac_roman(&header[2..2 + name_len]),
+            file_type: mac_roman(&header[65..69]),
+            creator: mac_roman(&header[69..73]),
+            data,
+            resources: resources(fork)?,
+        })
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ForkError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn kinds(&self) -> Vec<&str> {
+        let mut kinds = self.resources.iter().map(|resource| resource.kind.as_str()).collect::<Vec<_>>();
+        kinds.dedup();
+        kinds
+    }
+
+    pub fn get(&self, kind: &str, id: i16) -> Option<&Resource> {
+        self.resources.iter().find(|resource| resource.kind == kind && resource.id == id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn wrap(name: &str, fork: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0; HEADER];
+        bytes[1] = name.len() as u8;
+        bytes[2..2 + name.len()].copy_from_slice(name.as_bytes());
+        bytes[65..73].copy_from_slice(b"rsrcRSED");
+        bytes[87..91].copy_from_slice(&(fork.len() as u32).to_be_bytes());
+        bytes.extend(fork);
+        bytes
+    }
+
+    #[test]
+    fn read_a_fork() {
+        let mut fork = vec![0, 0, 0, 16, 0, 0, 0, 25, 0, 0, 0, 9, 0, 0, 0, 54];
+        fork.extend([0, 0, 0, 5, b'h', b'e', b'l', b'l', b'o']);
+        fork.extend([0; 24]);
+        fork.extend([0, 28, 0, 50]);
+        fork.extend([0, 0, b'S', b'T', b'R', b' ', 0, 0, 0, 10]);
+        fork.extend([0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        fork.extend([3, b'H', b'i', b'!']);
+        let read = Fork::from_bytes(&wrap("Greeting", &fork)).unwrap();
+        assert_eq!((read.name.as_str(), read.file_type.as_str(), read.creator.as_str()), ("Greeting", "rsrc", "RSED"));
+        assert_eq!(read.get("STR ", 128), Some(&Resource{kind: "STR ".into(), id: 128, name: Some("Hi!".into()), data: b"hello".to_vec()}));
+        assert!(matches!(Fork::from_bytes(&wrap("Greeting", &fork[..40])), Err(ForkError::BadMap(..))));
+        assert!(matches!(Fork::from_bytes(b"GLSV"), Err(ForkError::NotMacBinary)));
+
+        let art = Fork::open(Path::new(env!("CARGO_MANIFEST_DIR")).join("Glider_405").join("B&W Art.bin")).unwrap();
+        assert_eq!(art.kinds(), ["PICT", "deep"]);
+        assert_eq!(art.resources.len(), 16);
+        let pict = &art.get("PICT", 200).unwrap().data;
+        assert_eq!(pict[2..10], [0, 0, 0, 0, 1, 86, 2, 0]);
+    }
+}
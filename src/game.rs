@@ -1,15 +1,67 @@
 use sdl2::{keyboard::{KeyboardState, Scancode}, surface::Surface};
-use glider::{Entrance, Environment, Input, Outcome, Play, Room, Side, Update};
-use crate::{atlas, draw::{Animations, Frame, Scribe}, object, room::{self}};
-use std::{collections::HashMap, error::Error, fmt::Display, iter::repeat, num::NonZero, ops::Range, time::{Duration, Instant}};
+use glider::{CONTROLS, Cue, Ending, Entrance, Environment, Input, Outcome, Play, Prefs, Replay, Room, Save, SaveError, Side, fingerprint, Status, Success, Update};
+use crate::{atlas, clock::Clock, draw::{self, Animations, Banner, Frame, Popups, Scribe}, keys::{self, Bindings, Mouse, Pads}, object, room::{self}};
+use std::{collections::HashMap, error::Error, fmt::Display, iter::repeat, num::NonZero, ops::Range, path::PathBuf, time::Instant};
 
+pub const SAVE_FILE: &str = "glider.sav";
 const FADE_IN: &[usize] = &[3, 4, 3, 4, 5, 4, 5, 6, 5, 6, 7, 6, 7, 8, 7, 8, 9];
 const FADE_OUT: &[usize] = &[9, 8, 9, 8, 7, 8, 7, 6, 7, 6, 5, 6, 5, 4, 5, 4, 3];
 
 pub struct Game {
-    score: u32,
+    seed: u64,
+    status: Status,
     current_room: room::Id,
-    rooms: HashMap<room::Id, (Play, Surface<'static>)>,
+    rooms: HashMap<room::Id, (Play, [Surface<'static>; 2], String)>,
+    tape: Tape,
+    saves: PathBuf,
+    house: u32,
+}
+
+enum Tape {
+    Off,
+    Recording(Replay),
+    Playing(std::vec::IntoIter<Vec<Input>>),
+}
+
+impl Tape {
+    fn enter(&mut self, room: room::Id, at: Entrance) {
+        if let Tape::Recording(replay) = self { replay.enter(room, at) }
+    }
+}
+
+enum Pause {
+    Running,
+    Paused,
+    Quitting,
+    Saved(Result<(), String>),
+    Binding(usize, Prefs),
+}
+
+impl Pause {
+    fn banner(&self, keys: &Bindings) -> Option<(String, String)> {
+        let (title, hint) = match self {
+            Pause::Running => return None,
+            Pause::Paused => ("PAUSED", "P RESUME   S SAVE   K KEYS   ESC QUIT"),
+            Pause::Quitting => ("QUIT GAME?", "Y QUIT   N RESUME   S SAVE"),
+            Pause::Saved(Ok(())) => ("GAME SAVED", "P RESUME   ESC QUIT"),
+            Pause::Saved(Err(e)) => ("SAVE FAILED", e.as_str()),
+            Pause::Binding(n, _) => {
+                let now = keys.key(CONTROLS[*n]).map_or("NOTHING", |key| key.name());
+                return Some((format!("PRESS A KEY OR BUTTON TO {}", CONTROLS[*n].name()), format!("NOW {now}   ESC CANCEL")))
+            }
+        };
+        Some((title.to_string(), hint.to_string()))
+    }
+
+    fn learn(&mut self, bind: impl FnOnce(&mut Prefs, Input)) -> Option<Prefs> {
+        let Pause::Binding(n, prefs) = self else { return None };
+        bind(prefs, CONTROLS[*n]);
+        *n += 1;
+        if *n < CONTROLS.len() { return None }
+        let prefs = std::mem::take(prefs);
+        *self = Pause::Paused;
+        Some(prefs)
+    }
 }
 
 fn animate_with<F: FnOnce() -> Frame>(list: &Animations, id: usize, loader: F) {
@@ -25,12 +77,14 @@ fn recycle<I: Iterator<Item: Clone>>(iter: I, count: usize) -> impl Iterator<Ite
 enum PlayRoomError {
     UnknownRoom(room::Id),
     PlayerQuit,
+    GameOver(u32),
 }
 
 impl Display for PlayRoomError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::PlayerQuit => write!(f, "Player closed game"),
+            Self::GameOver(score) => write!(f, "Game over with {score} points"),
             #[cfg(debug_assertions)]
             Self::UnknownRoom(id) => write!(f, "Left house for pending room #{}", id.get()),
             #[cfg(not(debug_assertions))]
@@ -44,19 +98,26 @@ impl Error for PlayRoomError {}
 impl Game {
     pub fn run(&mut self, context: &mut crate::App, target: Entrance) -> Result<(u32, Option<(NonZero<u16>, Entrance)>), Box<dyn Error>> {
         let room = self.current_room;
-        let display = &mut context.display;
-        display.set_blend_mode(sdl2::render::BlendMode::Blend);
-        let creator = display.texture_creator();
-        let sprites = atlas::glider_sprites(context.sprites.as_ref().as_texture(&creator)?);
+        let canvas = &mut context.display;
+        canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+        let creator = canvas.texture_creator();
+        let sprites = context.art.iter().map(|art| Ok::<_, Box<dyn Error>>(art.over(art.as_ref().as_texture(&creator)?))).try_collect::<Vec<_>>()?;
 
-        let Some((play, wall)) = self.rooms.get_mut(&room) else { return Err(Box::new(PlayRoomError::UnknownRoom(room))) };
+        let kept = self.snapshot();
+        let status = &mut self.status;
+        let tape = &mut self.tape;
+        let saves = &self.saves;
+        let Some((play, walls, name)) = self.rooms.get_mut(&room) else { return Err(Box::new(PlayRoomError::UnknownRoom(room))) };
 
-        let wall = wall.as_texture(&creator)?;
-        let mut display = (display, &sprites);
+        let walls = walls.iter().map(|wall| wall.as_texture(&creator)).try_collect::<Vec<_>>()?;
 
-        play.reset(target);
+        play.enter(target);
+        play.charge(status.energy);
+        play.arm(status.bands);
+        tape.enter(room, target);
 
         let animation = Animations::default();
+        let popups = Popups::default();
         {
             let mut animation = animation.borrow_mut();
             for (id, object) in play.enumerate() {
@@ -74,53 +135,161 @@ impl Game {
             }
         }
 
-        let mut last = Instant::now();
+        let mut pause = Pause::Running;
+        let mut clock = Clock::new(context.speed, Instant::now());
         'game: loop {
-            while last.elapsed() < Duration::from_millis(33) {}
-            let mut inputs = Vec::new();
-            let keys = KeyboardState::new(&context.events);
-            let left = keys.is_scancode_pressed(Scancode::Left);
-            let right = keys.is_scancode_pressed(Scancode::Right);
-            if right {inputs.push(Input::Go(Side::Right))};
-            if left {inputs.push(Input::Go(Side::Left))};
+            clock.sleep();
+            let mut inputs = context.keys.held(&KeyboardState::new(&context.events), context.pads.as_ref().map_or(&[], Pads::connected));
+            let mut learned = None;
+            if let Some(mouse) = &mut context.mouse { mouse.grab(matches!(pause, Pause::Running)) }
             for event in context.events.poll_iter() {
-                use sdl2::event::Event;
-                match event {
+                use sdl2::event::{Event, WindowEvent};
+                if context.pads.as_mut().is_some_and(|pads| pads.plug(&event)) {
+                    if matches!((&event, &pause), (Event::ControllerDeviceRemoved{..}, Pause::Running)) { pause = Pause::Paused }
+                    continue
+                }
+                let key = match event {
                     Event::Quit{..} => break 'game,
-                    Event::KeyDown { scancode: Some(Scancode::Up), repeat: false, .. } => inputs.push(Input::Flip),
-                    Event::KeyDown { scancode: Some(Scancode::Escape), repeat: false, .. } => break 'game,
+                    Event::Window{win_event: WindowEvent::FocusLost, ..} if matches!(pause, Pause::Running) => { pause = Pause::Paused; continue }
+                    Event::Window{win_event: WindowEvent::SizeChanged(width, height), ..} => { crate::resize(&mut context.prefs, width, height); continue }
+                    Event::KeyDown { scancode: Some(crate::FULLSCREEN_KEY), repeat: false, .. } => { crate::toggle_fullscreen(canvas, &mut context.prefs); continue }
+                    Event::KeyDown { scancode: Some(crate::INTEGER_SCALE_KEY), repeat: false, .. } => { crate::toggle_integer_scale(canvas, &mut context.prefs); continue }
+                    Event::KeyDown { scancode: Some(crate::MONOCHROME_KEY), repeat: false, .. } => {
+                        context.prefs.monochrome = !context.prefs.monochrome;
+                        crate::keep_prefs(&context.prefs);
+                        continue
+                    }
+                    Event::KeyDown { scancode: Some(key), repeat: false, .. } => key,
+                    Event::MouseMotion { xrel, .. } if matches!(pause, Pause::Running) => {
+                        if let Some(mouse) = &mut context.mouse { mouse.nudge(xrel) }
+                        continue
+                    }
+                    Event::MouseButtonDown { mouse_btn, .. } if matches!(pause, Pause::Running) && context.mouse.is_some() => {
+                        inputs.extend(Mouse::clicked(mouse_btn));
+                        continue
+                    }
+                    Event::ControllerButtonDown { button, .. } => {
+                        match (&pause, button) {
+                            (Pause::Binding(..), keys::PAUSE_BUTTON) => pause = Pause::Paused,
+                            (Pause::Binding(..), button) => learned = pause.learn(|prefs, input| prefs.bind_button(input, button.string())),
+                            (Pause::Running, keys::PAUSE_BUTTON) => pause = Pause::Paused,
+                            (Pause::Running, button) => inputs.extend(context.keys.pushed(button)),
+                            (_, keys::PAUSE_BUTTON) => pause = Pause::Running,
+                            _ => (),
+                        }
+                        continue
+                    }
+                    _ => continue
+                };
+                if let Pause::Binding(..) = pause {
+                    match key {
+                        Scancode::Escape => pause = Pause::Paused,
+                        key if keys::RESERVED.contains(&key) => (),
+                        key => learned = pause.learn(|prefs, input| prefs.bind(input, key.name())),
+                    }
+                    continue
+                }
+                match (key, &pause) {
+                    (key, Pause::Running) if context.keys.pressed(key).is_some() => inputs.extend(context.keys.pressed(key)),
+                    (Scancode::P, Pause::Running) => pause = Pause::Paused,
+                    (Scancode::K, Pause::Paused) => pause = Pause::Binding(0, context.prefs.clone()),
+                    (Scancode::Escape | Scancode::Y, Pause::Quitting) => break 'game,
+                    (Scancode::Escape, _) => pause = Pause::Quitting,
+                    (Scancode::P | Scancode::N, _) => pause = Pause::Running,
+                    (Scancode::S, Pause::Paused | Pause::Quitting) => {
+                        let mut save = kept.clone();
+                        save.status = *status;
+                        save.rooms[room.get() as usize - 1] = play.changes().clone();
+                        pause = Pause::Saved(save.save(saves).map_err(|e| e.to_string()));
+                    }
                     _ => ()
                 }
             }
 
-            let result = play.frame(&inputs);
-
-            match result {
-                Outcome::Continue(updates) => {
-                    for update in updates.into_iter().flatten() {
-                        match update {
-                            Update::Turn(side) => animate_with(&animation, 0, || match side {Side::Right => Box::new(recycle((0..6).rev(), 2)), Side::Left => Box::new(recycle(0..6, 2))}),
-                            Update::Fade(inout) => animate_with(&animation, 0, || if inout {Box::new(FADE_IN.iter().cloned())} else {Box::new(FADE_OUT.iter().cloned())}),
-                            Update::Burn => animate_with(&animation, 0, || Box::new(atlas::BURN.cycle()) ),
-                            Update::Start(Environment::Grease, Some(bottle)) => animate_with(&animation, bottle.get(), 
-                                || Box::new(recycle(atlas::TIPPING..=atlas::TIPPING, 2))
-                            ),
-                            _ => ()
+            inputs.extend(context.mouse.as_ref().and_then(Mouse::thrust));
+
+            if let Some(prefs) = learned {
+                context.keys = Bindings::new(&prefs);
+                crate::keep_prefs(&prefs);
+                context.prefs = prefs;
+            }
+
+            let depth = context.prefs.monochrome as usize;
+            let (mut display, wall) = ((&mut *canvas, &sprites[depth]), &walls[depth]);
+            if let Some((title, hint)) = pause.banner(&context.keys) {
+                display.show(&sdl2::pixels::Color::RGB(0, 0, 0));
+                if !play.dark() {display.show(wall);}
+                display.show(&(&*play, &Animations::default())).show(&(&*status, name.as_str())).show(&Banner(&title, &hint)).publish();
+                clock.hold(Instant::now());
+                continue
+            }
+
+            let ticks = clock.ticks(Instant::now());
+            let held = inputs.iter().copied().filter(|input| keys::HELD.contains(input) || matches!(input, Input::Steer(..))).collect::<Vec<_>>();
+            for _ in 0..ticks {
+                let mut inputs = std::mem::replace(&mut inputs, held.clone());
+                inputs.retain(|input| status.permits(input));
+                match tape {
+                    Tape::Recording(replay) => replay.frame(&inputs),
+                    Tape::Playing(frames) => match frames.next() {
+                        Some(recorded) => inputs = recorded,
+                        None => break 'game,
+                    },
+                    Tape::Off => (),
+                }
+                draw::age_popups(&popups);
+                let result = play.frame(&inputs);
+
+                match result {
+                    Outcome::Continue(updates) => {
+                        let mut popped = false;
+                        for update in updates.into_iter().flatten() {
+                            if let Some(audio) = &mut context.audio {
+                                match update {
+                                    Update::Pop(id) => audio.play(match play[id].kind {
+                                        object::Kind::Downed(ref enemy) if matches!(**enemy, object::Kind::Balloon(..)) => Cue::Pop,
+                                        _ => Cue::Crunch,
+                                    }),
+                                    Update::Score(..) if popped => (),
+                                    update => if let Some(cue) = update.cue() { audio.play(cue) },
+                                }
+                            }
+                            popped |= matches!(update, Update::Pop(..));
+                            if let Update::Energy(remaining, Some(position)) = update {
+                                draw::float_the_score(&popups, remaining.saturating_sub(status.energy), position)
+                            }
+                            status.record(&update);
+                            match update {
+                                Update::Score(points, position) | Update::Life(points, position) => draw::float_the_score(&popups, points, position),
+                                Update::Bands(count, position) => draw::float_the_score(&popups, count as u16, position),
+                                Update::Turn(side) => animate_with(&animation, 0, || match side {Side::Right => Box::new(recycle((0..6).rev(), 2)), Side::Left => Box::new(recycle(0..6, 2))}),
+                                Update::Fade(inout) => animate_with(&animation, 0, || if inout {Box::new(FADE_IN.iter().cloned())} else {Box::new(FADE_OUT.iter().cloned())}),
+                                Update::Burn => animate_with(&animation, 0, || Box::new(atlas::BURN.cycle()) ),
+                                Update::Start(Environment::Grease, Some(bottle)) => animate_with(&animation, bottle.get(), 
+                                    || Box::new(recycle(atlas::TIPPING..=atlas::TIPPING, 2))
+                                ),
+                                _ => ()
+                            }
                         }
+                    },
+                    Outcome::Dead => {
+                        animation.borrow_mut().remove(&0);
+                        if !status.off_a_mortal() { return Err(Box::new(PlayRoomError::GameOver(status.score))) }
+                        let at = match target {Entrance::Flying(side, ..) => Entrance::Spawn(side), target => target};
+                        play.reset(at);
+                        tape.enter(room, at);
                     }
-                    display.show(&sdl2::pixels::Color::RGB(0, 0, 0));
-                    if !play.dark() {display.show(&wall);}
-                    display.show(&(&*play, &animation));
-                },
-                Outcome::Dead => {
-                    animation.borrow_mut().remove(&0);
-                    play.reset(match target {Entrance::Flying(side, ..) => Entrance::Spawn(side), target => target})
+                    Outcome::Leave{destination: Some((to_room, at)), ..} if to_room == room => {
+                        play.reset(at);
+                        tape.enter(room, at);
+                    }
+                    Outcome::Leave{score, destination} => return Ok((score, destination)),
+                    _ => ()
                 }
-                Outcome::Leave{destination: Some((to_room, at)), ..} if to_room == room => play.reset(at),
-                Outcome::Leave{score, destination} => return Ok((score, destination)),
-                _ => ()
             }
-            last = Instant::now();
+            display.show(&sdl2::pixels::Color::RGB(0, 0, 0));
+            if !play.dark() {display.show(wall);}
+            display.show(&(&*play, &animation)).show(&popups).show(&(&*status, name.as_str())).publish();
         }
         Err(Box::new(PlayRoomError::PlayerQuit))
     }
@@ -128,40 +297,88 @@ impl Game {
     pub fn play(&mut self, context: &mut crate::App) -> Result<(u32, NonZero<u16>), Box<dyn Error>> {
         let mut arrive = Entrance::default();
         while let (points, Some((next, at))) = {
-            let (room, _) = self.rooms.get_mut(&self.current_room).ok_or(PlayRoomError::UnknownRoom(self.current_room))?;
+            let (room, ..) = self.rooms.get_mut(&self.current_room).ok_or(PlayRoomError::UnknownRoom(self.current_room))?;
             eprintln!("Object count: {}", room.len());
             self.run(context, arrive)?
         } {
-            self.score += points;
+            self.status.score += points;
             if next.get() as usize > self.len() { return Err(Box::new(PlayRoomError::UnknownRoom(next))) }
             (self.current_room, arrive) = (next, at);
         } 
         eprintln!("Left house to {:?}", self.current_room);
-        Ok((self.score, self.current_room))
+        Ok((self.status.score, self.current_room))
     }
     fn len(&self) -> usize { self.rooms.len() }
+
+    pub fn success(&self, ended: &Result<(u32, NonZero<u16>), Box<dyn Error>>) -> Option<Success> {
+        let (score, level, room) = match ended {
+            Ok((score, _)) => (*score, 0, "Completed the House".to_string()),
+            Err(e) => match e.downcast_ref::<PlayRoomError>()? {
+                PlayRoomError::GameOver(score) => (*score, self.current_room.get(), self.rooms.get(&self.current_room)?.2.clone()),
+                _ => return None,
+            },
+        };
+        Some(Success{score, level, name: String::new(), room})
+    }
+
+    pub fn snapshot(&self) -> Save {
+        let mut rooms = self.rooms.iter().map(|(id, (play, ..))| (*id, play.changes().clone())).collect::<Vec<_>>();
+        rooms.sort_by_key(|(id, _)| *id);
+        Save{house: self.house, seed: self.seed, room: self.current_room, status: self.status, rooms: rooms.into_iter().map(|(_, changes)| changes).collect()}
+    }
+
+    pub fn resume(&mut self, save: &Save) -> Result<(), SaveError> {
+        save.fits(self.house, self.len())?;
+        for (i, changes) in save.rooms.iter().enumerate() {
+            if let Some((play, ..)) = self.rooms.get_mut(&NonZero::new(i as u16 + 1).unwrap()) { play.restore(changes) }
+        }
+        (self.status, self.current_room) = (save.status, save.room);
+        Ok(())
+    }
+
+    pub fn save_to(&mut self, path: impl Into<PathBuf>) { self.saves = path.into() }
+
+    pub fn record(&mut self) { self.tape = Tape::Recording(Replay::new(self.seed)) }
+
+    pub fn replay(&mut self, replay: &Replay) {
+        self.tape = Tape::Playing(replay.frames().map(<[Input]>::to_vec).collect::<Vec<_>>().into_iter())
+    }
+
+    pub fn ending(&self) -> Ending {
+        let player = self.rooms.get(&self.current_room).map(|(play, ..)| play.player().0).unwrap_or_default();
+        Ending::new(self.status, self.current_room, player)
+    }
+
+    pub fn recording(&mut self) -> Option<Replay> {
+        let Tape::Recording(mut replay) = std::mem::replace(&mut self.tape, Tape::Off) else { return None };
+        replay.finish(self.ending().checksum);
+        Some(replay)
+    }
 }
 
 impl crate::App {
-    pub fn prepare(&mut self, house: &[Room], themes: &HashMap<usize, Surface>) -> Result<Game, Box<dyn Error>> {
+    pub fn prepare(&mut self, house: &[Room], start: room::Id, themes: &[HashMap<usize, Surface>; 2], seed: u64) -> Result<Game, Box<dyn Error>> {
+        let paint = |r: &Room, depth: usize| {
+            let mut room = Surface::new(room::SCREEN_WIDTH, room::SCREEN_HEIGHT, self.display.default_pixel_format())?.into_canvas()?;
+            let processor = room.texture_creator();
+            let sprites = self.art[depth].over(self.art[depth].as_ref().as_texture(&processor)?);
+            (&mut room, &sprites).show(&(&themes[depth][&(r.theme_index() as usize)], r));
+            Ok::<_, Box<dyn Error>>(room.into_surface())
+        };
         Ok(Game{
-            score: 0,
-            #[cfg(not(debug_assertions))]
-            current_room: const{ NonZero::new(1).unwrap() },
-            #[cfg(debug_assertions)]
-            current_room: crate::test::START,
+            seed,
+            tape: Tape::Off,
+            saves: PathBuf::from(SAVE_FILE),
+            house: fingerprint(house),
+            status: Status::default(),
+            current_room: start,
             rooms: house.iter().enumerate().map(|(i, r)| 
                 Ok::<_, Box<dyn Error>> ((
                     NonZero::new(i as u16 + 1).unwrap(), 
                     (
-                        r.start(Entrance::default()), 
-                        {
-                            let mut room = Surface::new(room::SCREEN_WIDTH, room::SCREEN_HEIGHT, self.display.default_pixel_format())?.into_canvas()?;
-                            let processor = room.texture_creator();
-                            let sprites = atlas::glider_sprites(self.sprites.as_ref().as_texture(&processor)?);
-                            (&mut room, &sprites).show(&(&themes[&(r.theme_index() as usize)], r));
-                            room.into_surface()
-                        }
+                        r.start(Entrance::default(), seed.wrapping_add(i as u64)), 
+                        [paint(r, 0)?, paint(r, 1)?],
+                        r.name.clone(),
                     )
                 ))
             )
@@ -0,0 +1,99 @@
+// Set GOLDEN_UPDATE=1 to rewrite the snapshots in golden/ after an intended change to play or motion.
+use std::{fmt::Write, path::{Path, PathBuf}};
+
+use super::{Entrance, House, Input, Outcome, Room, Side, Update, Vertical, object::{Duct, Kind}};
+
+const FRAMES: u32 = 240;
+const SAMPLE: u32 = 20;
+const SEED: u64 = 1;
+const BANDS: u16 = 10;
+
+fn entrances(room: &Room) -> Vec<Entrance> {
+    let has = |found: fn(&Kind) -> bool| room.objects.iter().any(|object| found(&object.kind));
+    let mut at = vec![Entrance::Spawn(Side::Left)];
+    if room[Side::Left].is_some() { at.push(Entrance::Flying(Side::Left, 150)) }
+    if room[Side::Right].is_some() { at.extend([Entrance::Spawn(Side::Right), Entrance::Flying(Side::Right, 150)]) }
+    if has(|kind| matches!(kind, Kind::Stair(Vertical::Down, _))) { at.push(Entrance::Down) }
+    if has(|kind| matches!(kind, Kind::Stair(Vertical::Up, _))) { at.push(Entrance::Up) }
+    if has(|kind| matches!(kind, Kind::CeilingDuct(Duct::Travel(..)))) { at.push(Entrance::Air) }
+    at
+}
+
+fn script(name: &str, frame: u32) -> Vec<Input> {
+    match name {
+        "idle" => vec![],
+        "weave" => {
+            let mut inputs = vec![Input::Go(if (frame / 40).is_multiple_of(2) { Side::Right } else { Side::Left })];
+            if frame % 40 == 20 { inputs.push(Input::Flip) }
+            if frame % 60 == 30 { inputs.push(Input::Shoot) }
+            inputs
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn digest(text: &str) -> u32 {
+    text.bytes().fold(0x811C_9DC5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x0100_0193))
+}
+
+fn trajectory(out: &mut String, room: &Room, at: Entrance, name: &str) {
+    let mut play = room.start(at, SEED);
+    play.arm(BANDS);
+    for frame in 0..FRAMES {
+        let outcome = play.frame(&script(name, frame));
+        let (player, mode) = play.player();
+        let events = match &outcome {
+            Outcome::Continue(Some(updates)) => updates.iter().filter(|update| !matches!(update, Update::Fade(..) | Update::Turn(..) | Update::Burn)).collect(),
+            _ => vec![],
+        };
+        let done = !matches!(outcome, Outcome::Continue(..));
+        if frame % SAMPLE == 0 || !events.is_empty() || done {
+            let objects = digest(&play.enumerate().map(|(id, object)| format!("{}:{:?}", id.get(), object.position)).collect::<String>());
+            write!(out, "  {frame:>3} {:>4},{:<4} {objects:08x} {mode:?}", player.0, player.1).unwrap();
+            if !events.is_empty() { write!(out, " {events:?}").unwrap() }
+            if done { write!(out, " => {outcome:?}").unwrap() }
+            out.push('\n');
+        }
+        if done { break }
+    }
+}
+
+fn snapshot(house: &str) -> String {
+    let house = House::open(Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses").join(house)).unwrap();
+    let mut out = String::new();
+    for (index, room) in house.iter().enumerate() {
+        for at in entrances(room) {
+            for name in ["idle", "weave"] {
+                writeln!(out, "room {} {:?} from {at:?}, {name}", index + 1, room.name).unwrap();
+                trajectory(&mut out, room, at, name);
+            }
+        }
+    }
+    out
+}
+
+fn golden(house: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("golden").join(format!("{house}.txt"))
+}
+
+fn check(house: &str) {
+    let found = snapshot(house);
+    let path = golden(house);
+    if std::env::var_os("GOLDEN_UPDATE").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        return std::fs::write(&path, found).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}; run with GOLDEN_UPDATE=1 to create it", path.display()));
+    let mut heading = "";
+    for (line, (was, now)) in expected.lines().zip(found.lines()).enumerate() {
+        if was.starts_with("room ") { heading = was }
+        assert_eq!(was, now, "{} differs at line {} under \"{heading}\"", path.display(), line + 1);
+    }
+    assert_eq!(expected.lines().count(), found.lines().count(), "{} has a different number of lines", path.display());
+}
+
+#[test]
+fn golden_the_house() { check("The House") }
+
+#[test]
+fn golden_the_house_2() { check("The House 2") }
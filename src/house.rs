@@ -1,10 +1,13 @@
 use crate::object;
 
-use super::{Success, room::{self, Room}, prelude::Travel};
-use std::{ops::{AddAssign, Deref, Index}, time::SystemTime};
+use super::{Success, room::{self, Room}, prelude::Travel, import::{self, HouseExportError, HouseImportError, ImportMode, ImportReport}};
+use std::{fmt::Display, num::NonZero, ops::{AddAssign, Deref, Index, Range}, path::{Path, PathBuf}, time::SystemTime};
+
+pub const HI_SCORES: usize = 20;
 
 #[disclose]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct House {
     version: u16,
     time_stamp: SystemTime,
@@ -15,6 +18,102 @@ pub struct House {
     rooms: Vec<Room>,
 }
 
+#[disclose]
+#[derive(Debug, Clone)]
+pub struct Part {
+    name: String,
+    path: PathBuf,
+    rooms: Range<usize>,
+    report: ImportReport,
+}
+
+impl Part {
+    pub fn contains(&self, room: NonZero<u16>) -> bool { self.rooms.contains(&usize::from(room::Id(room))) }
+}
+
+#[derive(Debug)]
+pub enum HouseSetError {
+    MissingPart{name: String, after: String},
+    Cycle{name: String, after: String},
+    BadPart{name: String, error: HouseImportError},
+    TooManyRooms(usize),
+}
+
+impl Display for HouseSetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPart{name, after} => write!(f, "house \"{after}\" continues in \"{name}\", which could not be found"),
+            Self::Cycle{name, after} => write!(f, "house \"{after}\" continues in \"{name}\", which is already part of this house"),
+            Self::BadPart{name, error} => write!(f, "house part \"{name}\" could not be loaded: {error}"),
+            Self::TooManyRooms(count) => write!(f, "house parts hold {count} rooms in total, more than can be addressed"),
+        }
+    }
+}
+
+impl std::error::Error for HouseSetError {}
+
+impl House {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, HouseImportError> {
+        Ok(Self::open_with(path, ImportMode::Lenient)?.0)
+    }
+
+    pub fn open_with(path: impl AsRef<Path>, mode: ImportMode) -> Result<(Self, ImportReport), HouseImportError> {
+        let data = std::fs::read(path)?;
+        if data.len() != import::HOUSE_SIZE { return Err(HouseImportError::WrongFileSize(data.len())) }
+        Self::import(data.as_slice(), mode)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), HouseExportError> {
+        Ok(std::fs::write(path, Vec::try_from(self)?)?)
+    }
+
+    pub fn open_set(path: impl AsRef<Path>, mode: ImportMode) -> Result<(Self, Vec<Part>), HouseSetError> {
+        fn name_of(path: &Path) -> String { path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().to_string() }
+        let load = |path: &Path| {
+            House::open_with(path, mode).map_err(|error| HouseSetError::BadPart{name: name_of(path), error})
+        };
+
+        let path = path.as_ref();
+        let folder = path.parent().unwrap_or(Path::new(""));
+        let (mut house, report) = load(path)?;
+        let mut parts = vec![Part{name: name_of(path), path: path.canonicalize().unwrap_or(path.into()), rooms: 0..house.len(), report}];
+        while !house.next_file.is_empty() {
+            let (name, after) = (house.next_file.clone(), parts.last().map_or(String::new(), |p| p.name.clone()));
+            let next = folder.join(&name);
+            let Ok(next) = next.canonicalize() else { return Err(HouseSetError::MissingPart{name, after}) };
+            if parts.iter().any(|part| part.path == next) { return Err(HouseSetError::Cycle{name, after}) }
+            let (more, report) = load(&next)?;
+            let rooms = house.len()..(house.len() + more.len());
+            if rooms.end > u16::MAX as usize { return Err(HouseSetError::TooManyRooms(rooms.end)) }
+            house += more;
+            parts.push(Part{name, path: next, report: report.offset(rooms.start), rooms});
+        }
+        Ok((house, parts))
+    }
+}
+
+impl House {
+    pub fn rank(&self, score: u32) -> Option<usize> {
+        if score == 0 { return None }
+        let rank = self.hi_scores.iter().position(|best| score > best.score).unwrap_or(self.hi_scores.len());
+        (rank < HI_SCORES).then_some(rank)
+    }
+
+    pub fn enter_score(&mut self, success: Success) -> Option<usize> {
+        let rank = self.rank(success.score)?;
+        self.hi_scores.insert(rank, success);
+        self.hi_scores.truncate(HI_SCORES);
+        Some(rank)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl House {
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> { serde_json::from_str(text) }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> { serde_json::to_string_pretty(self) }
+}
+
 impl AddAssign for House {
     fn add_assign(&mut self, mut rhs: Self) {
         type Kind = object::Kind;
@@ -33,6 +132,7 @@ impl AddAssign for House {
             }
         }
         self.rooms.append(&mut rhs.rooms);
+        self.next_file = rhs.next_file;
     }
 }
 
@@ -1,4 +1,4 @@
-use std::{fmt::Display, num::NonZero, time::{Duration, SystemTime}};
+use std::{fmt::Display, mem::offset_of, num::NonZero, path::Path, time::{Duration, SystemTime}};
 
 use super::{*,
     room::{Exits, Room}, 
@@ -8,12 +8,39 @@ use super::{*,
     prelude::{Blow, Travel},
 };
 
+const MAC_ROMAN: [char; 128] = [
+    'Ä', 'Å', 'Ç', 'É', 'Ñ', 'Ö', 'Ü', 'á', 'à', 'â', 'ä', 'ã', 'å', 'ç', 'é', 'è',
+    'ê', 'ë', 'í', 'ì', 'î', 'ï', 'ñ', 'ó', 'ò', 'ô', 'ö', 'õ', 'ú', 'ù', 'û', 'ü',
+    '†', '°', '¢', '£', '§', '•', '¶', 'ß', '®', '©', '™', '´', '¨', '≠', 'Æ', 'Ø',
+    '∞', '±', '≤', '≥', '¥', 'µ', '∂', '∑', '∏', 'π', '∫', 'ª', 'º', 'Ω', 'æ', 'ø',
+    '¿', '¡', '¬', '√', 'ƒ', '≈', '∆', '«', '»', '…', '\u{00A0}', 'À', 'Ã', 'Õ', 'Œ', 'œ',
+    '–', '—', '“', '”', '‘', '’', '÷', '◊', 'ÿ', 'Ÿ', '⁄', '€', '‹', '›', 'ﬁ', 'ﬂ',
+    '‡', '·', '‚', '„', '‰', 'Â', 'Ê', 'Á', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', 'Ó', 'Ô',
+    '\u{F8FF}', 'Ò', 'Ú', 'Û', 'Ù', 'ı', 'ˆ', '˜', '¯', '˘', '˙', '˚', '¸', '˝', '˛', 'ˇ',
+];
+
+pub(crate) fn mac_roman(chars: &[u8]) -> String {
+    chars.iter().map(|&c| if c < 0x80 { c as char } else { MAC_ROMAN[c as usize - 0x80] }).collect()
+}
+
 fn string_from_pascal(bytes: &[u8]) -> String {
-    String::from_utf8_lossy(match bytes {
+    mac_roman(match bytes {
         [len, chars@..] if *len as usize <= chars.len() => &chars[..*len as usize],
         [_, chars@..] => chars,
         _ => return String::new()
-    }).to_string()
+    })
+}
+
+fn pascal_from_string<const N: usize>(text: &str) -> [u8; N] {
+    let mut pascal = [0; N];
+    let chars = text.chars()
+        .map(|c| if c.is_ascii() { c as u8 } else { MAC_ROMAN.iter().position(|&m| m == c).map_or(b'?', |i| i as u8 + 0x80) })
+        .take(N - 1);
+    for (i, c) in chars.enumerate() {
+        pascal[0] = i as u8 + 1;
+        pascal[i + 1] = c;
+    }
+    pascal
 }
 
 pub enum BadRectError{
@@ -44,6 +71,36 @@ type Block<T> = [u8; size_of::<T>()];
 pub const ROOM_SIZE: usize = size_of::<binary::Room>();
 pub const OBJECT_SIZE: usize = size_of::<binary::Object>();
 pub const HOUSE_SIZE: usize = size_of::<binary::House>();
+const SCORES_AT: usize = offset_of!(binary::HouseHeader, hi_scores);
+pub const SCORES_SIZE: usize = offset_of!(binary::HouseHeader, pict_name) - SCORES_AT;
+
+type ScoreTable = ([[u8; 4]; 20], [[u8; 2]; 20], [[u8; 26]; 20], [[u8; 26]; 20]);
+
+fn score_table(hi_scores: &[Success]) -> ScoreTable {
+    let mut scores = hi_scores.iter().map(Some).chain(std::iter::repeat(None));
+    let scores: [Option<&Success>; 20] = scores.next_chunk().unwrap();
+    (
+        scores.map(|s| s.map_or(0, |s| s.score).to_be_bytes()),
+        scores.map(|s| s.map_or(0, |s| s.level).to_be_bytes()),
+        scores.map(|s| pascal_from_string(s.map_or("", |s| &s.name))),
+        scores.map(|s| pascal_from_string(s.map_or("", |s| &s.room))),
+    )
+}
+
+fn successes(header: &binary::HouseHeader) -> Vec<Success> {
+    header.hi_scores.iter()
+        .zip(header.hi_level)
+        .zip(header.hi_names.iter().zip(header.hi_rooms.iter()))
+            .map(|((score, level), (name, room))|
+                Success{
+                    score: u32::from_be_bytes(*score),
+                    level: u16::from_be_bytes(level),
+                    name: string_from_pascal(name),
+                    room: string_from_pascal(room),
+                }
+            )
+    .collect()
+}
 
 mod binary {
     fn take_partition<I: IntoIterator, const PITCH: usize, const SIZE: usize>(i: I) -> [[I::Item; PITCH]; SIZE] where I::Item: core::fmt::Debug + Copy, [(); PITCH * SIZE]: {
@@ -166,6 +223,12 @@ mod binary {
          }
     }
 
+    impl AsRef<Block<Room>> for Room {
+        fn as_ref(&self) -> &Block<Room> {
+            unsafe { (self as *const _ as *const Block<Room>).as_ref().unwrap_unchecked() }
+        }
+    }
+
     impl From<Block<Room>> for Room {
         fn from(value: Block<Room>) -> Self { *value.as_ref() }
     }
@@ -263,6 +326,12 @@ mod binary {
         }
     }
 
+    impl AsRef<Block<House>> for House {
+        fn as_ref(&self) -> &Block<House> {
+            unsafe { (self as *const _ as *const Block<House>).as_ref().unwrap_unchecked() }
+        }
+    }
+
     impl FromIterator<u8> for House {
         fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
             let mut source = iter.into_iter();
@@ -304,7 +373,7 @@ impl object::Kind {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum BadObjectError {
+pub enum BadObjectError {
     FaultyDimensions(u16, u16, u16, u16),
     OutOfRoom(Bounds),
     UnknownKind(u16),
@@ -367,9 +436,9 @@ impl TryFrom<binary::Object> for Object {
             28 => Kind::Switch(amount.into(), 0..0),
             29 => Kind::Guitar,
 
-            32 => Kind::Drip{range: amount - bounds.top() as u16},
+            32 => Kind::Drip{range: amount - bounds.top() as u16, delay: extra},
             33 => Kind::Toaster{range: bounds.top() as u16 - amount, delay: extra},
-            34 => Kind::Ball({let mut range = object::Motion::new(-(bounds.bottom().saturating_sub_unsigned(amount) << 5), 0, 12); range.reset(); range}),
+            34 => Kind::Ball(object::Motion::new(-(bounds.bottom().saturating_sub_unsigned(amount) << 5), 0, 12)),
             35 => Kind::Fishbowl{range: bounds.y() as u16 - amount, delay: extra},
             36 => Kind::Teakettle{delay: amount},
             37 => Kind::Window(bounds.size(), ready),
@@ -391,23 +460,86 @@ impl TryFrom<binary::Object> for Object {
 }
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InvalidRoomError {
-    Fail,
+    WrongSize,
+    TooManyObjects(u16),
 }
 
+impl Display for InvalidRoomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongSize => write!(f, "room data is not {ROOM_SIZE} bytes long"),
+            Self::TooManyObjects(count) => write!(f, "room claims {count} objects; a room holds at most 16"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidRoomError {}
+
 impl<T> From<InvalidRoomError> for Result<T, InvalidRoomError> {
     fn from(value: InvalidRoomError) -> Self { Err(value) }
 }
 
 impl From<std::array::TryFromSliceError> for InvalidRoomError {
     fn from(_value: std::array::TryFromSliceError) -> Self {
-        Self::Fail
+        Self::WrongSize
     }
 }
 
 impl From<std::convert::Infallible> for InvalidRoomError {
-    fn from(_: std::convert::Infallible) -> Self { Self::Fail }
+    fn from(value: std::convert::Infallible) -> Self { match value {} }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportMode {
+    Strict,
+    #[default]
+    Lenient,
+}
+
+#[disclose]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectIssue {
+    room: usize,
+    slot: usize,
+    error: BadObjectError,
+}
+
+impl Display for ObjectIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "room {}, object {}: {}", self.room + 1, self.slot + 1, self.error)
+    }
+}
+
+#[disclose]
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    issues: Vec<ObjectIssue>,
+}
+
+impl ImportReport {
+    pub fn is_clean(&self) -> bool { self.issues.is_empty() }
+
+    pub(crate) fn offset(mut self, rooms: usize) -> Self {
+        self.issues.iter_mut().for_each(|issue| issue.room += rooms);
+        self
+    }
+}
+
+impl Display for ImportReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 { writeln!(f)? }
+            write!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::Deref for ImportReport {
+    type Target = [ObjectIssue];
+    fn deref(&self) -> &Self::Target { &self.issues }
 }
 
 struct EnemyCode(u16, i16);
@@ -433,15 +565,17 @@ impl From<(room::Id, [u8; 2])> for Exits {
     }
 }
 
-impl<T: TryInto<binary::Room>> TryFrom<(room::Id, T)> for Room where InvalidRoomError: From<<T as TryInto<binary::Room>>::Error> {
-    type Error = InvalidRoomError;
-    fn try_from((id, value): (room::Id, T)) -> Result<Self, Self::Error> {
+impl Room {
+    fn import(id: room::Id, value: binary::Room) -> Result<(Self, Vec<(usize, BadObjectError)>), InvalidRoomError> {
         use room::On;
-        let value = value.try_into()?;
         let header = &value.header;
-        let _n_objects@0..=16 = u16::from_be_bytes(header.object_count) else {
-            return InvalidRoomError::Fail.into()
+        let n_objects@0..=16 = u16::from_be_bytes(header.object_count) else {
+            return Err(InvalidRoomError::TooManyObjects(u16::from_be_bytes(header.object_count)))
         };
+        let (objects, issues): (Vec<_>, Vec<_>) = value.objects[..n_objects as usize].iter()
+            .enumerate()
+            .map(|(slot, o)| Object::try_from(*o).map_err(|error| (slot, error)))
+            .partition(Result::is_ok);
         let this = Self {
             name: string_from_pascal(&header.name),
             back_pict_id: u16::from_be_bytes(header.back_pict_id),
@@ -450,9 +584,16 @@ impl<T: TryInto<binary::Room>> TryFrom<(room::Id, T)> for Room where InvalidRoom
             animate: NonZero::new(u16::from_be_bytes(header.animate_number))
                 .zip(EnemyCode(u16::from_be_bytes(header.animate_kind), u32::from_be_bytes(header.animate_delay) as i16).into()),
             environs: On {air: header.condition_code[1] != 1, lights: header.condition_code[1] != 2},
-            objects: value.objects.into_iter().filter_map(|o| o.try_into().ok()).collect(),
+            objects: objects.into_iter().flatten().collect(),
         };
-        Ok(this)
+        Ok((this, issues.into_iter().filter_map(Result::err).collect()))
+    }
+}
+
+impl<T: TryInto<binary::Room>> TryFrom<(room::Id, T)> for Room where InvalidRoomError: From<<T as TryInto<binary::Room>>::Error> {
+    type Error = InvalidRoomError;
+    fn try_from((id, value): (room::Id, T)) -> Result<Self, Self::Error> {
+        Ok(Self::import(id, value.try_into()?)?.0)
     }
 }
 
@@ -463,59 +604,335 @@ impl<'a> TryFrom<(room::Id, &'a [u8])> for Room {
     }
 }
 
-impl TryFrom<binary::House> for House {
-    type Error = <Room as TryFrom<(room::Id, binary::Room)>>::Error;
-    fn try_from(value: binary::House) -> Result<Self, Self::Error> {
+impl House {
+    pub fn import(data: &[u8], mode: ImportMode) -> Result<(Self, ImportReport), HouseImportError> {
+        Self::from_binary(*<&binary::House>::try_from(data)?, mode)
+    }
+
+    fn from_binary(value: binary::House, mode: ImportMode) -> Result<(Self, ImportReport), HouseImportError> {
         let header = &value.header;
-        let n_rooms@0..=40 = u16::from_be_bytes(header.n_rooms) as usize else { return InvalidRoomError::Fail.into() };
-        Ok(Self {
+        let n_rooms@0..=40 = u16::from_be_bytes(header.n_rooms) as usize else {
+            return Err(HouseImportError::TooManyRooms(u16::from_be_bytes(header.n_rooms) as usize))
+        };
+        let mut report = ImportReport::default();
+        let mut rooms = Vec::with_capacity(n_rooms);
+        for (index, data) in value.rooms[..n_rooms].iter().enumerate() {
+            let (room, issues) = Room::import(room::Id::from(index), *data)
+                .map_err(|error| HouseImportError::MalformedRoom{room: index, error})?;
+            report.issues.extend(issues.into_iter().map(|(slot, error)| ObjectIssue{room: index, slot, error}));
+            rooms.push(room);
+        }
+        if mode == ImportMode::Strict && !report.is_clean() { return Err(HouseImportError::BadObjects(report)) }
+        let house = Self {
             version: u16::from_be_bytes(header.version),
             time_stamp: SystemTime::UNIX_EPOCH + Duration::from_secs(u32::from_be_bytes(header.time_stamp) as u64),
-            hi_scores: header.hi_scores.iter()
-                .zip(header.hi_level)
-                .zip(header.hi_names.iter().zip(header.hi_rooms.iter()))
-                    .map(|((score, level), (name, room))|
-                        Success{
-                            score: u32::from_be_bytes(*score),
-                            level: u16::from_be_bytes(level),
-                            name: string_from_pascal(name),
-                            room: string_from_pascal(room),
-                        }
-                    )
-            .collect(),
+            hi_scores: successes(header),
             pict_file: string_from_pascal(&header.pict_name),
             next_file: string_from_pascal(&header.next_file),
             first_file: string_from_pascal(&header.first_file),
-            rooms: value.rooms[..n_rooms]
-                .iter().enumerate()
-                .map(|(i, r)| Room::try_from((room::Id::from(i), *r))).try_collect()?
-        })
+            rooms,
+        };
+        Ok((house, report))
+    }
+}
+
+impl TryFrom<binary::House> for House {
+    type Error = HouseImportError;
+    fn try_from(value: binary::House) -> Result<Self, Self::Error> {
+        Ok(Self::from_binary(value, ImportMode::Lenient)?.0)
     }
 }
 
 #[derive(Debug)]
 pub enum HouseImportError {
     WrongDataSize(std::array::TryFromSliceError),
-    MalformedRoom(InvalidRoomError),
+    WrongFileSize(usize),
+    TooManyRooms(usize),
+    MalformedRoom{room: usize, error: InvalidRoomError},
+    BadObjects(ImportReport),
+    Unreadable(std::io::Error),
+}
+
+impl Display for HouseImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongDataSize(err) => write!(f, "house data is the wrong size ({err})"),
+            Self::WrongFileSize(size) => write!(f, "house file is {size} bytes long; expected {HOUSE_SIZE}"),
+            Self::TooManyRooms(count) => write!(f, "house claims {count} rooms; a house file holds at most 40"),
+            Self::MalformedRoom{room, error} => write!(f, "room {} is malformed ({error})", room + 1),
+            Self::BadObjects(report) => write!(f, "house contains {} unreadable objects:\n{report}", report.len()),
+            Self::Unreadable(err) => write!(f, "house file could not be read ({err})"),
+        }
+    }
+}
+
+impl std::error::Error for HouseImportError {}
+
+impl From<std::io::Error> for HouseImportError {
+    fn from(value: std::io::Error) -> Self { Self::Unreadable(value) }
 }
 
 impl From<std::array::TryFromSliceError> for HouseImportError {
     fn from(value: std::array::TryFromSliceError) -> Self { Self::WrongDataSize(value) }
 }
-impl From<InvalidRoomError> for HouseImportError {
-    fn from(value: InvalidRoomError) -> Self { Self::MalformedRoom(value) }
-}
 
 impl TryFrom<&[u8]> for House {
     type Error = HouseImportError;
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::try_from(*<&binary::House>::try_from(value)?)?)
+        Ok(Self::import(value, ImportMode::Lenient)?.0)
     }
 } 
 
+impl object::Kind {
+    const fn stored_size(&self) -> Option<Size> {
+        type Is = object::Kind;
+        match self {
+            Is::Table{width} => Size::new(width.get(), 9),
+            Is::Shelf{width} => Size::new(width.get(), 7),
+            Is::Cabinet(size) | Is::Exit{size, ..} | Is::Obstacle(size) |
+            Is::Bonus(_, size) | Is::Window(size, _) | Is::Mirror(size)
+                => Some(*size),
+            Is::Books => Size::new(64, 55),
+            Is::FloorVent{..} | Is::CeilingDuct(..) => Size::new(48, 13),
+            Is::CeilingVent{..} => Size::new(48, 12),
+            Is::Candle{..} => Size::new(32, 21),
+            Is::Fan{faces: Side::Left, ..} => Size::new(35, 55),
+            Is::Fan{faces: Side::Right, ..} => Size::new(35, 54),
+            Is::Clock(..) | Is::Grease{..} | Is::Fishbowl{..} => Size::new(32, 29),
+            Is::Paper(..) => Size::new(48, 21),
+            Is::Battery(..) | Is::Lights | Is::Switch(..) => Size::new(18, 26),
+            Is::RubberBands(..) => Size::new(32, 23),
+            Is::Outlet{..} => Size::new(32, 25),
+            Is::Thermostat => Size::new(18, 27),
+            Is::Shredder{..} => Size::new(64, 24),
+            Is::Guitar => Size::new(64, 170),
+            Is::Drip{..} => Size::new(16, 13),
+            Is::Toaster{..} => Size::new(38, 27),
+            Is::Ball(..) => Size::new(32, 32),
+            Is::Teakettle{..} => Size::new(41, 30),
+            Is::Painting => Size::new(102, 93),
+            Is::Basket => Size::new(63, 71),
+            Is::Macintosh => Size::new(45, 58),
+            Is::Stair(..) => Size::new(161, 254),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum HouseExportError {
+    TooManyRooms(usize),
+    TooManyObjects{room: usize, count: usize},
+    UnwritableObject{room: usize, kind: object::Kind},
+    Unwritable(std::io::Error),
+}
+
+impl HouseExportError {
+    fn in_room(self, index: usize) -> Self {
+        match self {
+            Self::TooManyObjects{count, ..} => Self::TooManyObjects{room: index, count},
+            Self::UnwritableObject{kind, ..} => Self::UnwritableObject{room: index, kind},
+            other => other,
+        }
+    }
+}
+
+impl Display for HouseExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooManyRooms(count) => write!(f, "house has {count} rooms; a house file holds at most 40"),
+            Self::TooManyObjects{room, count} => write!(f, "room {} has {count} objects; a room holds at most 16", room + 1),
+            Self::UnwritableObject{room, kind} => write!(f, "room {} holds {kind:?}, which has no house file representation", room + 1),
+            Self::Unwritable(err) => write!(f, "house file could not be written ({err})"),
+        }
+    }
+}
+
+impl std::error::Error for HouseExportError {}
+
+impl From<std::io::Error> for HouseExportError {
+    fn from(value: std::io::Error) -> Self { Self::Unwritable(value) }
+}
+
+impl TryFrom<&Object> for binary::Object {
+    type Error = HouseExportError;
+    fn try_from(value: &Object) -> Result<Self, Self::Error> {
+        use object::Kind;
+        let unwritable = || HouseExportError::UnwritableObject{room: 0, kind: value.kind.clone()};
+        let size = value.kind.stored_size().ok_or_else(unwritable)?;
+        let bounds = (size / value.kind.display_anchor()) << *value.position;
+        let (top, left, bottom, right) = (bounds.top() as u16, bounds.left() as u16, bounds.bottom() as u16, bounds.right() as u16);
+        let (object_is, amount, extra, ready): (u16, u16, u16, bool) = match &value.kind {
+            Kind::Table{..} => (1, 0, 0, false),
+            Kind::Shelf{..} => (2, 0, 0, false),
+            Kind::Books => (3, 0, 0, false),
+            Kind::Cabinet(..) => (4, 0, 0, false),
+            Kind::Exit{to, ..} => (5, to.map_or(0, |room::Id(to)| to.get()), 0, false),
+            Kind::Obstacle(..) => (6, 0, 0, false),
+
+            Kind::FloorVent{height} => (8, top.wrapping_sub(*height), 0, false),
+            Kind::CeilingVent{height} => (9, bottom.wrapping_add(*height), 0, false),
+            Kind::CeilingDuct(Blow(height)) => (10, bottom.wrapping_add(*height), 0, true),
+            Kind::CeilingDuct(Travel(to)) => (10, 0, to.map_or(0, |room::Id(to)| to.get()), false),
+            Kind::Candle{height} => (11, top.wrapping_sub(*height), 0, false),
+            Kind::Fan{faces: Side::Left, range, ready} => (12, left.wrapping_sub(*range), 0, *ready),
+            Kind::Fan{faces: Side::Right, range, ready} => (13, right.wrapping_add(*range), 0, *ready),
+
+            Kind::Clock(amount) => (16, *amount, 0, false),
+            Kind::Paper(amount) => (17, *amount, 0, false),
+            Kind::Grease{progress, ready} => (18, (progress.end - 1 + bounds.right()) as u16, 0, *ready),
+            Kind::Bonus(amount, _) => (19, *amount, 0, false),
+            Kind::Battery(amount) => (20, *amount as u16, 0, false),
+            Kind::RubberBands(amount) => (21, *amount as u16, 0, false),
+
+            Kind::Lights => (24, 0, 0, false),
+            Kind::Outlet{progress} => (25, progress.end as u16, 0, false),
+            Kind::Thermostat => (26, 0, 0, false),
+            Kind::Shredder{ready} => (27, 0, 0, *ready),
+            Kind::Switch(target, _) => (28, target.get() as u16, 0, false),
+            Kind::Guitar => (29, 0, 0, false),
+
+            Kind::Drip{range, delay} => (32, top.wrapping_add(*range), *delay, false),
+            Kind::Toaster{range, delay} => (33, top.wrapping_sub(*range), *delay, false),
+            Kind::Ball(bounce) => (34, bottom.wrapping_sub((-bounce.limit.start >> 5) as u16), 0, false),
+            Kind::Fishbowl{range, delay} => (35, (bounds.y() as u16).wrapping_sub(*range), *delay, false),
+            Kind::Teakettle{delay} => (36, *delay, 0, false),
+            Kind::Window(_, ready) => (37, 0, 0, *ready),
+
+            Kind::Painting => (40, 0, 0, false),
+            Kind::Mirror(..) => (41, 0, 0, false),
+            Kind::Basket => (42, 0, 0, false),
+            Kind::Macintosh => (43, 0, 0, false),
+            Kind::Stair(Vertical::Up, to) => (44, to.0.get(), 0, false),
+            Kind::Stair(Vertical::Down, to) => (45, to.0.get(), 0, false),
+            _ => return Err(unwritable()),
+        };
+        Ok(Self {
+            object_is: object_is.to_be_bytes(),
+            bounds: [top, left, bottom, right].map(u16::to_be_bytes),
+            amount: amount.to_be_bytes(),
+            extra: extra.to_be_bytes(),
+            is_on: ready as u8,
+            _fill: 0,
+        })
+    }
+}
+
+impl From<&object::Kind> for Option<EnemyCode> {
+    fn from(value: &object::Kind) -> Self {
+        type Use = object::Kind;
+        Some(match value {
+            Use::Dart(delay) => EnemyCode(0, delay.end),
+            Use::Copter(delay) => EnemyCode(1, delay.end),
+            Use::Balloon(delay) => EnemyCode(2, delay.end),
+            _ => return None,
+        })
+    }
+}
+
+impl TryFrom<&Room> for binary::Room {
+    type Error = HouseExportError;
+    fn try_from(value: &Room) -> Result<Self, Self::Error> {
+        let count @ 0..=16 = value.objects.len() else { return Err(HouseExportError::TooManyObjects{room: 0, count: value.objects.len()}) };
+        let mut objects = [binary::Object::default(); 16];
+        for (slot, object) in objects.iter_mut().zip(&value.objects) {
+            *slot = object.try_into()?;
+        }
+        let (number, EnemyCode(kind, delay)) = value.animate.as_ref()
+            .and_then(|(number, kind)| Some((number.get(), Option::<EnemyCode>::from(kind)?)))
+            .unwrap_or((0, EnemyCode(0, 0)));
+        Ok(Self {
+            header: binary::RoomHeader {
+                name: pascal_from_string(&value.name),
+                object_count: (count as u16).to_be_bytes(),
+                back_pict_id: value.back_pict_id.to_be_bytes(),
+                tile_order: value.tile_order.map(|n| [0, n]),
+                left_right_open: [value.exits.left.is_some() as u8, value.exits.right.is_some() as u8],
+                animate_kind: kind.to_be_bytes(),
+                animate_number: number.to_be_bytes(),
+                animate_delay: (delay as u16 as u32).to_be_bytes(),
+                condition_code: [0, match value.environs {room::On{air: false, ..} => 1, room::On{lights: false, ..} => 2, _ => 0}],
+            },
+            objects,
+        })
+    }
+}
+
+impl TryFrom<&House> for binary::House {
+    type Error = HouseExportError;
+    fn try_from(value: &House) -> Result<Self, Self::Error> {
+        let n_rooms @ 0..=40 = value.rooms.len() else { return Err(HouseExportError::TooManyRooms(value.rooms.len())) };
+        let mut rooms = [binary::Room::from_iter([0; ROOM_SIZE]); 40];
+        for (index, (slot, room)) in rooms.iter_mut().zip(&value.rooms).enumerate() {
+            *slot = room.try_into().map_err(|e: HouseExportError| e.in_room(index))?;
+        }
+        let (hi_scores, hi_level, hi_names, hi_rooms) = score_table(&value.hi_scores);
+        Ok(Self {
+            header: binary::HouseHeader {
+                version: value.version.to_be_bytes(),
+                n_rooms: (n_rooms as u16).to_be_bytes(),
+                time_stamp: (value.time_stamp.duration_since(SystemTime::UNIX_EPOCH).map_or(0, |since| since.as_secs()) as u32).to_be_bytes(),
+                hi_scores, hi_level, hi_names, hi_rooms,
+                pict_name: pascal_from_string(&value.pict_file),
+                next_file: pascal_from_string(&value.next_file),
+                first_file: pascal_from_string(&value.first_file),
+            },
+            rooms,
+        })
+    }
+}
+
+impl House {
+    // Scores go into a sidecar holding just that part of the header, or back into the header of a house file.
+    // Either way the file is replaced whole by renaming a finished copy over it, so a crash can't leave it half written.
+    pub fn save_scores(&self, path: impl AsRef<Path>) -> Result<(), HouseExportError> {
+        let (scores, level, names, rooms) = score_table(&self.hi_scores);
+        let table = [scores.as_flattened(), level.as_flattened(), names.as_flattened(), rooms.as_flattened()].concat();
+        let data = match std::fs::read(&path) {
+            Ok(mut data) if data.len() == HOUSE_SIZE => { data[SCORES_AT..][..SCORES_SIZE].copy_from_slice(&table); data }
+            Ok(data) if data.len() != SCORES_SIZE => return Err(HouseExportError::Unwritable(std::io::Error::new(std::io::ErrorKind::InvalidData, "file holds neither a house nor its scores"))),
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => table,
+        };
+        let mut partial = path.as_ref().as_os_str().to_owned();
+        partial.push(".partial");
+        std::fs::write(&partial, data)?;
+        Ok(std::fs::rename(partial, path)?)
+    }
+
+    pub fn load_scores(&mut self, path: impl AsRef<Path>) -> Result<(), HouseImportError> {
+        let data = std::fs::read(path)?;
+        let mut header = [0; size_of::<binary::HouseHeader>()];
+        match data.len() {
+            HOUSE_SIZE => header.copy_from_slice(&data[..size_of::<binary::HouseHeader>()]),
+            SCORES_SIZE => header[SCORES_AT..][..SCORES_SIZE].copy_from_slice(&data),
+            size => return Err(HouseImportError::WrongFileSize(size)),
+        }
+        self.hi_scores = successes(&binary::HouseHeader::from_iter(header));
+        Ok(())
+    }
+}
+
+impl TryFrom<&House> for Vec<u8> {
+    type Error = HouseExportError;
+    fn try_from(value: &House) -> Result<Self, Self::Error> {
+        let data = binary::House::try_from(value)?;
+        Ok(AsRef::<Block<binary::House>>::as_ref(&data).to_vec())
+    }
+}
+
+impl TryFrom<&Room> for Vec<u8> {
+    type Error = HouseExportError;
+    fn try_from(value: &Room) -> Result<Self, Self::Error> {
+        let data = binary::Room::try_from(value)?;
+        Ok(AsRef::<Block<binary::Room>>::as_ref(&data).to_vec())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::HouseSetError;
 
     const DATA_A: &[u8] = include_bytes!("resources/The House");
     const DATA_B: &[u8] = include_bytes!("resources/The House 2");
@@ -624,6 +1041,205 @@ mod test {
         }
     }
 
+    #[test]
+    fn open_house_files() {
+        let houses = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses");
+        let house = House::open(houses.join("Usher House")).unwrap();
+        assert_eq!(house.len(), 30);
+        assert_eq!(house.first_file, "Usher House");
+        assert!(matches!(House::open(houses.join("Usher House Read Me.txt")), Err(HouseImportError::WrongFileSize(1345))));
+        assert!(matches!(House::open(houses.join("No Such House")), Err(HouseImportError::Unreadable(..))));
+    }
+
+    #[test]
+    fn open_house_sets() {
+        let houses = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses");
+        let (house, parts) = House::open_set(houses.join("House of the Rising Sun"), ImportMode::Lenient).unwrap();
+        assert_eq!(parts.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), ["House of the Rising Sun", "House of the Rising Sun 2", "House of the Rising Sun 3"]);
+        assert_eq!(house.len(), 120);
+        assert!(parts[1].contains(NonZero::new(41).unwrap()) && !parts[1].contains(NonZero::new(40).unwrap()));
+        assert!(house.next_file.is_empty());
+
+        let (house, parts) = House::open_set(houses.join("Combo House 2"), ImportMode::Lenient).unwrap();
+        assert_eq!((house.len(), parts.len()), (23 + 40 + 24, 3));
+        let (house, _) = House::open_set(houses.join("Usher House"), ImportMode::Lenient).unwrap();
+        assert_eq!(house.len(), 30);
+    }
+
+    #[test]
+    fn report_unreadable_objects() {
+        let houses = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses");
+        let (house, report) = House::open_with(houses.join("Glass House"), ImportMode::Lenient).unwrap();
+        assert_eq!(report.iter().map(|issue| (issue.room, issue.slot)).collect::<Vec<_>>(), [(27, 0), (27, 1)]);
+        assert!(report.iter().all(|issue| matches!(issue.error, BadObjectError::FaultyDimensions(257, 7, 0, 0))));
+        assert_eq!(house.rooms[27].len(), 14);
+        assert!(matches!(House::open_with(houses.join("Glass House"), ImportMode::Strict), Err(HouseImportError::BadObjects(report)) if report.len() == 2));
+
+        let Err(HouseImportError::BadObjects(report)) = House::open_with(houses.join("Usher House"), ImportMode::Strict) else { panic!("Usher House should fail strict import") };
+        assert!(report.len() == 1 && report.to_string().starts_with("room 18, object 1: "));
+        assert!(House::import(DATA_A, ImportMode::Strict).unwrap().1.is_clean());
+
+        let (_, parts) = House::open_set(houses.join("Combo House 2"), ImportMode::Lenient).unwrap();
+        let last = parts.last().unwrap();
+        assert_eq!(last.name, "Combo House 4");
+        assert_eq!(last.report.iter().map(|issue| (issue.room, issue.slot)).collect::<Vec<_>>(), [(69, 12), (83, 2), (85, 9)]);
+        assert!(last.report.iter().all(|issue| matches!(issue.error, BadObjectError::OutOfRoom(..))));
+        assert!(matches!(House::open_set(houses.join("Combo House 2"), ImportMode::Strict), Err(HouseSetError::BadPart{name, ..}) if name == "Combo House 4"));
+    }
+
+    #[test]
+    fn reject_broken_house_sets() {
+        let folder = std::env::temp_dir().join(format!("glider-sets-{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        let mut data = DATA_B.to_vec();
+        let link = |data: &mut Vec<u8>, name: &str| {
+            let next = &mut data[1202..1236];
+            next.fill(0);
+            next[0] = name.len() as u8;
+            next[1..=name.len()].copy_from_slice(name.as_bytes());
+        };
+        link(&mut data, "Loop B");
+        std::fs::write(folder.join("Loop A"), &data).unwrap();
+        link(&mut data, "Loop A");
+        std::fs::write(folder.join("Loop B"), &data).unwrap();
+        link(&mut data, "Nowhere");
+        std::fs::write(folder.join("Dangling"), &data).unwrap();
+
+        assert!(matches!(House::open_set(folder.join("Loop A"), ImportMode::Lenient), Err(HouseSetError::Cycle{name, ..}) if name == "Loop A"));
+        assert!(matches!(House::open_set(folder.join("Dangling"), ImportMode::Lenient), Err(HouseSetError::MissingPart{name, ..}) if name == "Nowhere"));
+        std::fs::remove_dir_all(folder).ok();
+    }
+
+    // What a house file holds that import deliberately doesn't keep; everything else has to come back byte for byte:
+    // bytes past a Pascal string's length, `_fill`, slots past the room and object counts, the enemy of a room without one,
+    // objects import rejects, the setting a duct's mode ignores, zero exit and switch targets, and the width of 16-pixel switches.
+    #[test]
+    fn round_trip_house_files() {
+        let houses = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses");
+        let live = |pascal: &[u8]| pascal[..(pascal[0] as usize + 1).min(pascal.len())].to_vec();
+        let mut checked = 0usize;
+        for path in std::fs::read_dir(houses).unwrap().map(|entry| entry.unwrap().path()) {
+            let Ok(house) = House::open(&path) else { continue };
+            let written = Vec::try_from(&house).unwrap();
+            assert_eq!(Vec::try_from(&House::try_from(written.as_slice()).unwrap()).unwrap(), written, "{path:?} does not re-read as written");
+
+            let (canonical, written) = (binary::House::from_iter(std::fs::read(&path).unwrap()), binary::House::from_iter(written));
+            let (header, out) = (&canonical.header, &written.header);
+            assert_eq!((header.version, header.n_rooms, header.time_stamp, header.hi_scores, header.hi_level), (out.version, out.n_rooms, out.time_stamp, out.hi_scores, out.hi_level), "{path:?} header");
+            let names = |header: &binary::HouseHeader| header.hi_names.iter().chain(&header.hi_rooms).map(|name| live(name))
+                .chain([&header.pict_name, &header.next_file, &header.first_file].map(|name| live(name))).collect::<Vec<_>>();
+            assert_eq!(names(header), names(out), "{path:?} header names");
+
+            for (index, (room, out)) in canonical.rooms.iter().zip(&written.rooms).enumerate() {
+                let at = format!("{path:?} room {}", index + 1);
+                if index >= house.len() {
+                    assert!(out.header.name == [0; 26] && out.header.object_count == [0; 2], "{at} is past the room count");
+                    continue
+                }
+                let (header, written_header) = (&room.header, &out.header);
+                assert_eq!(live(&header.name), live(&written_header.name), "{at}");
+                assert_eq!((header.back_pict_id, header.tile_order, header.left_right_open, header.animate_number, header.condition_code),
+                    (written_header.back_pict_id, written_header.tile_order, written_header.left_right_open, written_header.animate_number, written_header.condition_code), "{at}");
+                if header.animate_number != [0; 2] {
+                    assert_eq!((header.animate_kind, header.animate_delay), (written_header.animate_kind, written_header.animate_delay), "{at} enemy");
+                }
+
+                let count = u16::from_be_bytes(header.object_count) as usize;
+                let kept = room.objects[..count].iter().filter(|&&object| Object::try_from(object).is_ok()).collect::<Vec<_>>();
+                assert_eq!(u16::from_be_bytes(written_header.object_count) as usize, kept.len(), "{at} object count");
+                assert!(out.objects[kept.len()..].iter().all(|object| object.object_is == [0; 2]), "{at} is past the object count");
+                for (slot, (object, out)) in kept.into_iter().zip(&out.objects).enumerate() {
+                    let at = format!("{at} object {}", slot + 1);
+                    assert_eq!((object.object_is, object.is_on), (out.object_is, out.is_on), "{at}");
+                    let [_, left, _, right] = object.bounds.map(u16::from_be_bytes);
+                    match u16::from_be_bytes(object.object_is) {
+                        20 | 24 | 26 | 28 if right - left == 16 => assert_eq!(Object::try_from(*object).unwrap().position, Object::try_from(*out).unwrap().position, "{at}"),
+                        _ => assert_eq!(object.bounds, out.bounds, "{at}"),
+                    }
+                    match u16::from_be_bytes(object.object_is) {
+                        10 if object.is_on != 0 => assert_eq!(object.amount, out.amount, "{at}"),
+                        10 => assert_eq!(object.extra, out.extra, "{at}"),
+                        5 | 28 if object.amount == [0; 2] => assert_eq!((out.amount, out.extra), (1u16.to_be_bytes(), object.extra), "{at}"),
+                        _ => assert_eq!((object.amount, object.extra), (out.amount, out.extra), "{at}"),
+                    }
+                }
+            }
+            checked += 1;
+        }
+        assert_eq!(checked, 22);
+    }
+
+    #[test]
+    fn normalise_lossy_objects() {
+        let stored = |object_is: u16, [top, left, bottom, right]: [u16; 4], amount: u16| binary::Object{
+            object_is: object_is.to_be_bytes(), bounds: [top, left, bottom, right].map(u16::to_be_bytes), amount: amount.to_be_bytes(), ..Default::default()
+        };
+        let again = |object: binary::Object| binary::Object::try_from(&Object::try_from(object).unwrap()).unwrap();
+
+        // A zero target reads as the first room or object, and is written back as such.
+        for object in [stored(5, [100, 200, 150, 250], 0), stored(28, [100, 200, 126, 218], 0)] {
+            assert_eq!(again(object).amount, 1u16.to_be_bytes());
+        }
+        let exit = stored(5, [100, 200, 150, 250], 7);
+        assert_eq!((again(exit).amount, again(exit).bounds), (exit.amount, exit.bounds));
+
+        // Early editors drew switches and batteries 16 pixels wide; they come back at full width about the same anchor.
+        for (object, full) in [(stored(28, [100, 200, 126, 216], 3), [100, 199, 126, 217]), (stored(20, [100, 200, 126, 216], 5), [100, 199, 126, 217])] {
+            assert_eq!((again(object).bounds, again(object).amount), (full.map(u16::to_be_bytes), object.amount));
+        }
+        let switch = stored(28, [100, 200, 126, 218], 3);
+        assert_eq!(again(switch).bounds, switch.bounds);
+    }
+
+    #[test]
+    fn keep_hi_scores() {
+        let mut house = House::try_from(DATA_A).unwrap();
+        assert_eq!((house.hi_scores.len(), house.hi_scores[0].score), (20, 370152));
+        assert_eq!((house.rank(0), house.rank(1)), (None, Some(11)));
+        assert_eq!(house.rank(u32::MAX), Some(0));
+        assert_eq!(house.enter_score(Success{score: 150000, level: 12, name: "Glider Pilot".into(), room: "Attica?".into()}), Some(2));
+        assert_eq!((house.hi_scores.len(), &*house.hi_scores[2].name, house.hi_scores[3].score), (20, "Glider Pilot", 149548));
+        assert_eq!(house.rank(1), Some(12));
+
+        let folder = std::env::temp_dir().join(format!("glider-scores-{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        let (copy, sidecar) = (folder.join("The House"), folder.join("The House.scores"));
+        std::fs::write(&copy, DATA_A).unwrap();
+        house.save_scores(&copy).unwrap();
+        house.save_scores(&sidecar).unwrap();
+        assert_eq!(std::fs::metadata(&sidecar).unwrap().len(), SCORES_SIZE as u64);
+        assert_eq!(std::fs::read_dir(&folder).unwrap().count(), 2);
+        let reread = House::open(&copy).unwrap();
+        assert_eq!(Vec::try_from(&reread).unwrap()[SCORES_AT + SCORES_SIZE..], Vec::try_from(&House::try_from(DATA_A).unwrap()).unwrap()[SCORES_AT + SCORES_SIZE..]);
+        let mut fresh = House::try_from(DATA_A).unwrap();
+        fresh.load_scores(&sidecar).unwrap();
+        for scores in [&reread.hi_scores, &fresh.hi_scores] {
+            assert_eq!(scores.iter().map(|s| (s.score, &*s.name)).collect::<Vec<_>>(), house.hi_scores.iter().map(|s| (s.score, &*s.name)).collect::<Vec<_>>());
+        }
+        std::fs::remove_dir_all(folder).ok();
+    }
+
+    #[test]
+    fn reject_unwritable_houses() {
+        let mut house = House::try_from(DATA_A).unwrap();
+        house += House::try_from(DATA_B).unwrap();
+        assert!(matches!(Vec::try_from(&house), Err(HouseExportError::TooManyRooms(63))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trip_house_text() {
+        let houses = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses");
+        for path in std::fs::read_dir(houses).unwrap().map(|entry| entry.unwrap().path()) {
+            let Ok(house) = House::open(&path) else { continue };
+            let text = house.to_json().unwrap();
+            let reread = House::from_json(&text).unwrap();
+            assert_eq!(Vec::try_from(&reread).unwrap(), Vec::try_from(&house).unwrap(), "{path:?} does not survive as text");
+        }
+        let text = House::try_from(DATA_A).unwrap().to_json().unwrap();
+        assert!(text.contains(r#""Fan": {"#) && text.contains(r#""faces": "Left""#));
+    }
+
     #[test]
     fn validate_house_passthrough() {
         let house = DATA_A.as_chunks().0[0];
@@ -631,4 +1247,4 @@ mod test {
         let target = &test as *const _ as *const Block<binary::House>;
         assert!((&unsafe{*target}) == &house);
     }
-}
\ No newline at end of file
+}
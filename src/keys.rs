@@ -0,0 +1,113 @@
+use glider::{Input, MAX_THRUST, Prefs, Side};
+use sdl2::{GameControllerSubsystem, controller::{Axis, Button, GameController}, event::Event, keyboard::{KeyboardState, Scancode}, mouse::{MouseButton, MouseUtil}};
+
+pub const RESERVED: [Scancode; 5] = [Scancode::Escape, Scancode::P, Scancode::F9, Scancode::F10, Scancode::F11];
+pub const PAUSE_BUTTON: Button = Button::Start;
+pub const HELD: [Input; 3] = [Input::Go(Side::Right), Input::Go(Side::Left), Input::Zoom];
+const STICK: i32 = i16::MAX as i32;
+const AIM: i32 = 100 * 100;
+
+pub struct Bindings {
+    keys: Vec<(Scancode, Input)>,
+    buttons: Vec<(Button, Input)>,
+    dead_zone: i16,
+}
+
+pub struct Pads {
+    subsystem: GameControllerSubsystem,
+    open: Vec<GameController>,
+}
+
+pub struct Mouse {
+    util: MouseUtil,
+    aim: i32,
+    sensitivity: i32,
+}
+
+fn bindings<T: PartialEq>(prefs: &Prefs, named: impl Fn(&Prefs, Input) -> Option<T>, kind: &str, shown: fn(&Prefs, Input) -> Option<&str>) -> Vec<(T, Input)> {
+    let stock = Prefs::default();
+    glider::CONTROLS.iter().filter_map(|&input| {
+        let bound = named(prefs, input).or_else(|| {
+            eprintln!("warning: \"{}\" isn't a {kind} Glider can bind to {}; using {}", shown(prefs, input).unwrap_or_default(), input.name(), shown(&stock, input).unwrap_or_default());
+            named(&stock, input)
+        })?;
+        Some((bound, input))
+    }).collect()
+}
+
+impl Bindings {
+    pub fn new(prefs: &Prefs) -> Self {
+        Self{
+            keys: bindings(prefs, |prefs, input| prefs.key(input).and_then(Scancode::from_name).filter(|key| !RESERVED.contains(key)), "key", Prefs::key),
+            buttons: bindings(prefs, |prefs, input| prefs.button(input).and_then(Button::from_string).filter(|button| *button != PAUSE_BUTTON), "button", Prefs::button),
+            dead_zone: (prefs.dead_zone.min(99) as i32 * STICK / 100) as i16,
+        }
+    }
+
+    pub fn held(&self, keys: &KeyboardState, pads: &[GameController]) -> Vec<Input> {
+        HELD.into_iter().filter(|input| {
+            self.keys.iter().any(|(key, bound)| bound == input && keys.is_scancode_pressed(*key)) ||
+            self.buttons.iter().any(|(button, bound)| bound == input && pads.iter().any(|pad| pad.button(*button))) ||
+            pads.iter().any(|pad| match (input, pad.axis(Axis::LeftX)) {
+                (Input::Go(Side::Left), lean) => lean < -self.dead_zone,
+                (Input::Go(Side::Right), lean) => lean > self.dead_zone,
+                _ => false,
+            })
+        }).collect()
+    }
+
+    pub fn pressed(&self, key: Scancode) -> Option<Input> {
+        self.keys.iter().find(|(bound, input)| *bound == key && !HELD.contains(input)).map(|(_, input)| *input)
+    }
+
+    pub fn pushed(&self, button: Button) -> Option<Input> {
+        self.buttons.iter().find(|(bound, input)| *bound == button && !HELD.contains(input)).map(|(_, input)| *input)
+    }
+
+    pub fn key(&self, input: Input) -> Option<Scancode> {
+        self.keys.iter().find(|(_, bound)| *bound == input).map(|(key, _)| *key)
+    }
+}
+
+impl Pads {
+    pub fn new(subsystem: GameControllerSubsystem) -> Self { Self{subsystem, open: Vec::new()} }
+
+    pub fn connected(&self) -> &[GameController] { &self.open }
+
+    // SDL announces controllers already plugged in at startup the same way as ones plugged in later.
+    pub fn plug(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::ControllerDeviceAdded{which, ..} => match self.subsystem.open(which) {
+                Ok(pad) if self.open.iter().all(|open| open.instance_id() != pad.instance_id()) => self.open.push(pad),
+                Ok(_) => (),
+                Err(e) => eprintln!("warning: couldn't open controller {which} ({e})"),
+            },
+            Event::ControllerDeviceRemoved{which, ..} => self.open.retain(|pad| pad.instance_id() != which),
+            _ => return false
+        }
+        true
+    }
+}
+
+impl Mouse {
+    pub fn new(util: MouseUtil, prefs: &Prefs) -> Self { Self{util, aim: 0, sensitivity: prefs.sensitivity as i32} }
+
+    pub fn grab(&mut self, flying: bool) {
+        if self.util.relative_mouse_mode() != flying { self.util.set_relative_mouse_mode(flying) }
+    }
+
+    // As with the original's CenterMouse, the glider leans by how far the pointer has strayed from centre; 100 pixels is full thrust.
+    pub fn nudge(&mut self, xrel: i32) { self.aim = (self.aim + xrel * self.sensitivity).clamp(-AIM, AIM) }
+
+    pub fn thrust(&self) -> Option<Input> {
+        Some(Input::Steer((self.aim * MAX_THRUST as i32 / AIM) as i16)).filter(|steer| *steer != Input::Steer(0))
+    }
+
+    pub fn clicked(button: MouseButton) -> Option<Input> {
+        match button {
+            MouseButton::Left => Some(Input::Flip),
+            MouseButton::Right => Some(Input::Shoot),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 #![feature(
-    iter_next_chunk, slice_as_chunks, iterator_try_collect, is_none_or,
+    iter_next_chunk, slice_as_chunks, is_none_or,
     iter_advance_by, iter_collect_into, const_try,
     const_option,
     generic_arg_infer, generic_const_exprs, const_refs_to_cell
@@ -50,15 +50,18 @@ pub type Reference = cart::Point;
 pub type Displacement = cart::Displacement;
 pub type Size = cart::Size;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Input {
     Go(Side),
+    Steer(i16),
     Flip,
     Shoot,
     Zoom,
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Environment {
     Ball,
     Outlet,
@@ -72,13 +75,15 @@ pub enum Environment {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Update {
     Score(u16, Position),
     Life(u16, Position),
     Bands(u8, Position),
-    Energy(u8, Position),
+    Energy(u16, Option<Position>),
     Shoot,
     Zoom,
+    Pop(object::Id),
     Start(Environment, Option<object::Id>),
     Bump,
     Fade(bool),
@@ -89,6 +94,7 @@ pub enum Update {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Outcome {
     Continue(Option<Vec<Update>>),
     Dead,
@@ -96,6 +102,7 @@ pub enum Outcome {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Success {
     pub score: u32,
     pub level: u16,
@@ -104,6 +111,7 @@ pub struct Success {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Side {
     Left, Right,
 }
@@ -141,6 +149,7 @@ impl Neg for &Side {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Vertical {
     Down, Up,
 }
@@ -161,14 +170,37 @@ impl Neg for &Vertical {
 }
 
 pub use room::Room;
-pub use house::House;
+pub use house::{HI_SCORES, House, HouseSetError, Part};
 pub use object::Object;
-pub use play::{Entrance, Play};
+pub use play::{DEFAULT_DRAIN, Entrance, MAX_THRUST, Play};
+pub use import::{BadObjectError, HouseExportError, HouseImportError, ImportMode, ImportReport, InvalidRoomError, ObjectIssue};
+pub use lint::Lint;
+pub use status::Status;
+pub use sim::{simulate, Step};
+pub use replay::{Ending, Mark, Replay, ReplayError};
+pub use save::{Changes, Save, SaveError, fingerprint};
+pub use fork::{Fork, ForkError, Resource};
+pub use prefs::{CONTROLS, Prefs, PrefsError};
+pub use rng::Rng;
+pub use sound::{Cue, Sample, SoundError, rez_resources};
+pub use pict::{Bitmap, PictError, SHEET_HEIGHT, SHEET_WIDTH, picture, sprite_sheet};
 
 mod object;
 mod room;
 mod house;
+mod lint;
 
 mod play;
+mod status;
+mod replay;
+mod save;
+mod fork;
+mod prefs;
+mod sim;
+mod rng;
+mod sound;
+mod pict;
 
 mod import;
+#[cfg(test)]
+mod golden;
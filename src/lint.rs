@@ -0,0 +1,133 @@
+use std::{collections::VecDeque, fmt::Display, num::NonZero};
+
+use super::{Side, house::House, object::{Kind, Object}, prelude::Travel, room};
+
+// Furniture drawn resting on other furniture commonly sinks into it by a pixel or two.
+const RESTING_OVERLAP: u16 = 4;
+
+#[derive(Debug, Clone)]
+pub enum Lint {
+    MissingRoom{room: usize, slot: Option<usize>, to: NonZero<u16>},
+    MissingObject{room: usize, slot: usize, target: NonZero<usize>},
+    Untoggleable{room: usize, slot: usize, target: NonZero<usize>, kind: Kind},
+    Unreachable{room: usize},
+    Overlap{room: usize, slots: (usize, usize)},
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingRoom{room, slot: None, to} => write!(f, "room {} has an open side leading to room {to}, which doesn't exist", room + 1),
+            Self::MissingRoom{room, slot: Some(slot), to} => write!(f, "room {}, object {} leads to room {to}, which doesn't exist", room + 1, slot + 1),
+            Self::MissingObject{room, slot, target} => write!(f, "room {}, object {} switches object {target}, which doesn't exist", room + 1, slot + 1),
+            Self::Untoggleable{room, slot, target, kind} => write!(f, "room {}, object {} switches object {target}, which can't be toggled ({kind:?})", room + 1, slot + 1),
+            Self::Unreachable{room} => write!(f, "room {} can't be reached from the start", room + 1),
+            Self::Overlap{room, slots: (a, b)} => write!(f, "room {}, objects {} and {} overlap", room + 1, a + 1, b + 1),
+        }
+    }
+}
+
+impl Object {
+    fn is_solid(&self) -> bool {
+        matches!(self.kind, Kind::Table{..} | Kind::Shelf{..} | Kind::Books | Kind::Cabinet(..) | Kind::Obstacle(..) | Kind::Basket | Kind::Macintosh)
+    }
+
+    fn is_toggleable(&self) -> bool {
+        matches!(self.kind, Kind::Fan{..} | Kind::Shredder{..})
+    }
+
+    fn destination(&self) -> Option<room::Id> {
+        match self.kind {
+            Kind::Exit{to, ..} | Kind::CeilingDuct(Travel(to)) => to,
+            Kind::Stair(_, to) => Some(to),
+            _ => None,
+        }
+    }
+}
+
+impl House {
+    pub fn lint(&self, start: NonZero<u16>) -> Vec<Lint> {
+        let exists = |id: room::Id| usize::from(id) < self.len();
+        let mut found = Vec::new();
+        let mut links = vec![Vec::new(); self.len()];
+        for (index, room) in self.iter().enumerate() {
+            for to in [Side::Left, Side::Right].into_iter().filter_map(|side| room[side]) {
+                if exists(to) { links[index].push(usize::from(to)) } else { found.push(Lint::MissingRoom{room: index, slot: None, to: to.0}) }
+            }
+            for (slot, object) in room.objects.iter().enumerate() {
+                if let Some(to) = object.destination() {
+                    if exists(to) { links[index].push(usize::from(to)) } else { found.push(Lint::MissingRoom{room: index, slot: Some(slot), to: to.0}) }
+                }
+                if let Kind::Switch(target, _) = object.kind {
+                    match room.objects.get(usize::from(target)) {
+                        None => found.push(Lint::MissingObject{room: index, slot, target: *target}),
+                        Some(other) if !other.is_toggleable() => found.push(Lint::Untoggleable{room: index, slot, target: *target, kind: other.kind.clone()}),
+                        Some(_) => (),
+                    }
+                }
+            }
+            let solid = room.objects.iter().enumerate().filter(|(_, o)| o.is_solid()).filter_map(|(i, o)| Some((i, o.active_area()?))).collect::<Vec<_>>();
+            for (n, &(a, bounds)) in solid.iter().enumerate() {
+                for &(b, other) in &solid[n + 1..] {
+                    let Some(shared) = bounds & other else { continue };
+                    if shared.width().get().min(shared.height().get()) > RESTING_OVERLAP { found.push(Lint::Overlap{room: index, slots: (a, b)}) }
+                }
+            }
+        }
+
+        let mut reached = vec![false; self.len()];
+        let mut queue = VecDeque::from_iter(exists(room::Id(start)).then(|| usize::from(room::Id(start))));
+        while let Some(index) = queue.pop_front() {
+            if std::mem::replace(&mut reached[index], true) { continue }
+            queue.extend(links[index].iter().filter(|&&to| !reached[to]));
+        }
+        found.extend(reached.iter().enumerate().filter(|(_, &seen)| !seen).map(|(room, _)| Lint::Unreachable{room}));
+        found
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn open(name: &str) -> House {
+        House::open_set(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses").join(name), crate::ImportMode::Lenient).unwrap().0
+    }
+
+    const START: NonZero<u16> = NonZero::new(1).unwrap();
+
+    #[test]
+    fn lint_broken_links() {
+        let found = open("Hands-off House").lint(START);
+        let forty_one = NonZero::new(41).unwrap();
+        assert!(found.iter().any(|lint| matches!(lint, Lint::MissingRoom{room: 39, slot: None, to} if *to == forty_one)));
+        assert!(found.iter().any(|lint| matches!(lint, Lint::MissingRoom{room: 39, slot: Some(0), to} if *to == forty_one)));
+        let switched = found.iter().filter_map(|lint| match lint {
+            Lint::Untoggleable{room: 24, slot, target, kind: Kind::CeilingDuct(..)} if target.get() == 7 => Some(*slot),
+            _ => None,
+        });
+        assert_eq!(switched.collect::<Vec<_>>(), [7, 9, 10]);
+        assert!(!found.iter().any(|lint| matches!(lint, Lint::Unreachable{..} | Lint::MissingObject{..})));
+    }
+
+    #[test]
+    fn lint_unreachable_rooms() {
+        let mut house = open("The House");
+        assert!(house.lint(START).is_empty());
+        let last = room::Id::from(house.len() - 1);
+        for room in house.rooms.iter_mut() {
+            room.exits.left = room.exits.left.filter(|&to| to != last);
+            room.exits.right = room.exits.right.filter(|&to| to != last);
+            room.objects.retain(|object| object.destination() != Some(last));
+        }
+        let found = house.lint(START);
+        assert!(matches!(found[..], [Lint::Unreachable{room}] if room == house.len() - 1));
+    }
+
+    #[test]
+    fn lint_overlapping_furniture() {
+        let found = open("Glass House").lint(START);
+        let piled = found.iter().filter(|lint| matches!(lint, Lint::Overlap{room: 12, ..})).count();
+        assert_eq!(piled, 13);
+    }
+}
@@ -6,7 +6,7 @@
 
 use std::error::Error;
 
-use sdl2::{image::LoadTexture, render::Canvas, surface::Surface, video::Window, EventPump};
+use sdl2::{render::Canvas, surface::Surface, video::{FullscreenType, Window}, EventPump};
 
 mod room {
 	use glider::prelude::room;
@@ -19,45 +19,185 @@ mod room {
 
 static mut DEBUG: bool = false;
 
+const STOCK_NAME: &str = "The House";
+const STOCK_SCORES: &str = "The House.scores";
+const SCORES: &str = ".scores";
+
 fn show_debug() -> bool { unsafe{ DEBUG } }
 
 use glider::prelude::object;
 
 mod space;
 mod resources;
+mod audio;
+mod clock;
 mod game;
 mod atlas;
 mod draw;
+mod font;
+mod keys;
+mod scores;
 mod test;
 
 use atlas::Atlas;
 
 struct App {
     display: Canvas<Window>,
-    sprites: Atlas<Surface<'static>>,
+    art: [Atlas<Surface<'static>>; 2],
     events: EventPump,
+    audio: Option<audio::Audio>,
+    prefs: glider::Prefs,
+    keys: keys::Bindings,
+    pads: Option<keys::Pads>,
+    mouse: Option<keys::Mouse>,
+    speed: u32,
+}
+
+fn keep_prefs(prefs: &glider::Prefs) {
+    let Some(path) = glider::Prefs::path() else { return eprintln!("warning: nowhere to keep preferences; set HOME or XDG_CONFIG_HOME") };
+    if let Err(e) = prefs.save(&path) { eprintln!("warning: couldn't save preferences to \"{}\" ({e})", path.display()) }
+}
+
+const FULLSCREEN_KEY: sdl2::keyboard::Scancode = sdl2::keyboard::Scancode::F11;
+const MONOCHROME_KEY: sdl2::keyboard::Scancode = sdl2::keyboard::Scancode::F10;
+const INTEGER_SCALE_KEY: sdl2::keyboard::Scancode = sdl2::keyboard::Scancode::F9;
+
+impl App {
+    fn sprites(&self) -> &Atlas<Surface<'static>> { &self.art[self.prefs.monochrome as usize] }
+}
+
+fn toggle_fullscreen(display: &mut Canvas<Window>, prefs: &mut glider::Prefs) {
+    let mode = if prefs.fullscreen { FullscreenType::Off } else { FullscreenType::Desktop };
+    if let Err(e) = display.window_mut().set_fullscreen(mode) { return eprintln!("warning: couldn't switch to {mode:?} ({e})") }
+    prefs.fullscreen = !prefs.fullscreen;
+    keep_prefs(prefs);
+}
+
+fn toggle_integer_scale(display: &mut Canvas<Window>, prefs: &mut glider::Prefs) {
+    if let Err(e) = display.set_integer_scale(!prefs.integer_scale) { return eprintln!("warning: couldn't change integer scaling ({e})") }
+    prefs.integer_scale = !prefs.integer_scale;
+    keep_prefs(prefs);
+}
+
+// A window dragged to a new size opens at the nearest whole multiple of the screen next time; fullscreen leaves the windowed scale alone.
+fn resize(prefs: &mut glider::Prefs, width: i32, height: i32) {
+    let scale = (width / room::SCREEN_WIDTH as i32).min(height / room::SCREEN_HEIGHT as i32).clamp(1, u8::MAX as i32) as u8;
+    if prefs.fullscreen || prefs.scale == scale { return }
+    prefs.scale = scale;
+    keep_prefs(prefs);
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
     if let Some(_debug) = std::env::args().find(|flag| flag == "--debug") {
         unsafe {DEBUG = true}
     }
+    let mut prefs = glider::Prefs::path()
+        .map(|path| glider::Prefs::open(&path).map_err(|e| eprintln!("warning: ignored preferences in \"{}\" ({e})", path.display())).unwrap_or_default())
+        .unwrap_or_default();
+    if let Some(controls) = std::env::args().find_map(|flag| flag.strip_prefix("--controls=").map(str::to_string)) {
+        prefs.mouse = match controls.as_str() {
+            "mouse" => true,
+            "keys" => false,
+            _ => return Err(format!("--controls takes \"mouse\" or \"keys\", not \"{controls}\"").into()),
+        };
+        keep_prefs(&prefs);
+    }
     let sdl = sdl2::init().unwrap();
-    let window = sdl.video().unwrap().window("Glider", room::SCREEN_WIDTH, room::SCREEN_HEIGHT).build().unwrap();
-    let display = window.into_canvas().present_vsync().build().unwrap();
-    let sprites = {
-        let mut sprites = Surface::new(512, 598, display.default_pixel_format())?.into_canvas()?;
-        let creator = sprites.texture_creator();
-        let pixels = creator.load_texture_bytes(resources::color::SPRITES)?;
-        sprites.copy(&pixels, None, None).ok();
-        sprites.into_surface()
-    };
+    let scale = prefs.scale as u32;
+    sdl2::hint::set("SDL_RENDER_SCALE_QUALITY", "nearest");
+    let mut window = sdl.video().unwrap().window("Glider", room::SCREEN_WIDTH * scale, room::SCREEN_HEIGHT * scale);
+    window.resizable();
+    if prefs.fullscreen { window.fullscreen_desktop(); }
+    let mut display = window.build().unwrap().into_canvas().present_vsync().build().unwrap();
+    display.set_logical_size(room::SCREEN_WIDTH, room::SCREEN_HEIGHT)?;
+    display.set_integer_scale(prefs.integer_scale)?;
+    let art = [atlas::sprites(false, display.default_pixel_format())?, atlas::sprites(true, display.default_pixel_format())?];
     let mut app = App {
         display,
-        sprites: atlas::glider_sprites(sprites),
+        art,
         events: sdl.event_pump().unwrap(),
+        audio: {
+            let channels = std::env::args().find_map(|flag| flag.strip_prefix("--channels=")?.parse().ok()).unwrap_or(audio::CHANNELS);
+            sdl.audio().and_then(|audio| audio::Audio::open(&audio, channels, prefs.volume))
+                .map_err(|e| eprintln!("warning: playing without sound ({e})")).ok()
+        },
+        keys: keys::Bindings::new(&prefs),
+        pads: sdl.game_controller().map(keys::Pads::new).map_err(|e| eprintln!("warning: playing without controllers ({e})")).ok(),
+        mouse: prefs.mouse.then(|| keys::Mouse::new(sdl.mouse(), &prefs)),
+        speed: match std::env::args().find_map(|flag| flag.strip_prefix("--speed=").map(str::to_string)) {
+            Some(speed) => speed.parse().ok().filter(|speed| (10..=1000).contains(speed)).ok_or_else(|| format!("--speed takes a percentage from 10 to 1000, not \"{speed}\""))?,
+            None => 100,
+        },
+        prefs,
+    };
+    let chosen = std::env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    let (mut house, start, scores, title) = match chosen.clone().or_else(|| Some(app.prefs.house.as_ref().filter(|path| path.exists())?.to_string_lossy().to_string())) {
+        Some(path) => {
+            let mode = if std::env::args().any(|flag| flag == "--strict") { glider::ImportMode::Strict } else { glider::ImportMode::Lenient };
+            let (house, parts) = glider::House::open_set(&path, mode).map_err(|e| format!("Couldn't open house \"{path}\": {e}"))?;
+            for part in &parts {
+                eprintln!("Rooms {}-{} from \"{}\"", part.rooms.start + 1, part.rooms.end, part.name);
+                for issue in part.report.iter() {
+                    eprintln!("warning: skipped {issue}");
+                }
+            }
+            let path = std::fs::canonicalize(&parts[0].path).unwrap_or_else(|_| parts[0].path.clone());
+            if chosen.is_some() && app.prefs.house.as_ref() != Some(&path) {
+                app.prefs.house = Some(path.clone());
+                keep_prefs(&app.prefs);
+            }
+            let mut scores = path.into_os_string();
+            scores.push(SCORES);
+            (house, const{ room::Id::new(1).unwrap() }, scores.into(), parts[0].name.clone())
+        }
+        #[cfg(not(debug_assertions))]
+        None => (test::stock_house(), const{ room::Id::new(1).unwrap() }, std::path::PathBuf::from(STOCK_SCORES), STOCK_NAME.to_string()),
+        #[cfg(debug_assertions)]
+        None => (test::stock_house(), test::START, std::path::PathBuf::from(STOCK_SCORES), STOCK_NAME.to_string()),
+    };
+    if scores.exists() {
+        house.load_scores(&scores).map_err(|e| eprintln!("warning: ignored high scores in \"{}\" ({e})", scores.display())).ok();
+    }
+    let flag = |name: &str| std::env::args().find_map(|arg| Some(arg.strip_prefix(name)?.strip_prefix('=')?.to_string()));
+    let replay = flag("--replay").map(|path| glider::Replay::open(&path).map_err(|e| format!("Couldn't open replay \"{path}\": {e}"))).transpose()?;
+    let saves = flag("--save").unwrap_or(game::SAVE_FILE.to_string());
+    let resume = std::env::args().any(|flag| flag == "--resume")
+        .then(|| glider::Save::open(&saves).map_err(|e| format!("Couldn't resume saved game \"{saves}\": {e}"))).transpose()?;
+    let seed = match (&replay, &resume) {
+        (Some(replay), _) => replay.seed,
+        (None, Some(saved)) => saved.seed,
+        (None, None) => flag("--seed").and_then(|seed| seed.parse().ok()).unwrap_or_else(|| glider::Rng::from_clock().seed()),
     };
-    let mut this_game = app.prepare(&test::stock_house(), &atlas::rooms()).expect("Couldn't load game");
-    this_game.play(&mut app).ok();
+    eprintln!("Seed: {seed}");
+    let mut this_game = app.prepare(&house, start, &[atlas::rooms(false), atlas::rooms(true)], seed).expect("Couldn't load game");
+    this_game.save_to(&saves);
+    if let Some(saved) = &resume {
+        this_game.resume(saved).map_err(|e| format!("Couldn't resume saved game \"{saves}\": {e}"))?;
+        eprintln!("Resumed in room {} with {} points", saved.room, saved.status.score);
+    }
+    match &replay {
+        Some(replay) => this_game.replay(replay),
+        None if flag("--record").is_some() => this_game.record(),
+        None => (),
+    }
+    let ended = this_game.play(&mut app);
+    if let Some(mut success) = this_game.success(&ended).filter(|_| replay.is_none()) {
+        let rank = match house.rank(success.score) {
+            Some(_) => app.enter_name(success.score)?.and_then(|name| { success.name = name; house.enter_score(success) }),
+            None => None,
+        };
+        if rank.is_some() {
+            house.save_scores(&scores).map_err(|e| eprintln!("warning: couldn't save high scores to \"{}\" ({e})", scores.display())).ok();
+        }
+        app.show_scores(&title, &house.hi_scores, rank)?;
+    }
+    if let (Some(path), Some(recording)) = (flag("--record"), this_game.recording()) {
+        recording.save(&path).map_err(|e| format!("Couldn't save replay \"{path}\": {e}"))?;
+        eprintln!("Recorded {} frames to \"{path}\"", recording.frames().count());
+    }
+    if let Some(expected) = replay.and_then(|replay| replay.checksum) {
+        let ending = this_game.ending();
+        eprintln!("Replay {} (checksum {:016x}, recorded {expected:016x})", if ending.checksum == expected {"matches"} else {"differs"}, ending.checksum);
+    }
     Ok(())
 }
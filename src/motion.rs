@@ -1,9 +1,10 @@
 use std::ops::Range;
 
-use crate::{object, room, Displacement, Interval, Object, Position};
+use crate::{object, room, Displacement, Interval, Object, Position, Rng};
 
 #[disclose]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Motion<const N: usize = 0> {
     limit: Interval,
     acceleration: i16,
@@ -67,6 +68,8 @@ impl Iterator for object::Kind {
             Is::Balloon(delay) => delay.next().is_none().then_some( (0, -3) )?, 
             Is::Copter(delay) =>  delay.next().is_none().then_some( (-4, 2) )?,
             Is::Dart(delay) =>    delay.next().is_none().then_some( (-8, 1) )?,
+            Is::Downed(..) => (0, 12),
+            Is::Band(side) => (*side * 16, 0),
             Is::Grease { ready: false, progress } => {progress.next(); return None},
             Is::Outlet { progress } => {if let None = progress.next() {progress.start = -30;} return None},
             Is::Steam { progress } => {if let None = progress.next() {progress.start = -10;} return None},
@@ -78,32 +81,36 @@ impl Iterator for object::Kind {
 }
 
 impl Object {
-    pub fn advance(&mut self) {
+    pub fn advance(&mut self, rng: &mut Rng) {
         if let Some(offset) = self.kind.next() {
             type Is = object::Kind;
             self.position += offset;
             match self.kind {
                 Is::Balloon(..) | Is::Copter(..) | Is::Dart(..) if (self.active_area() & room::BOUNDS).is_none() 
-                    => self.reset(),
+                    => self.reset(rng),
+                Is::Downed(..) if (self.active_area() & room::BOUNDS).is_none() => {
+                    if let Is::Downed(enemy) = std::mem::replace(&mut self.kind, Is::Flame) { self.kind = *enemy }
+                    self.reset(rng)
+                }
                 _ => ()
             };
         }
     }
 
-    fn reset(&mut self) {
-        if let Some(position) = self.kind.reset(0) {
+    fn reset(&mut self, rng: &mut Rng) {
+        if let Some(position) = self.kind.reset(0, rng) {
             self.position = position;
         }
     }
 }
 
 impl object::Kind {
-    fn reset(&mut self, delay: i16) -> Option<Position> {
+    fn reset(&mut self, delay: i16, rng: &mut Rng) -> Option<Position> {
         type Is = object::Kind;
         let (start, position) = match self {
-            Is::Balloon(Range{start, ..}) => (start, (random() % 400 + 50, 358)),
-            Is::Copter(Range{start, ..}) => (start, (random() % 256 + 272, -16)),
-            Is::Dart(Range{start, ..}) => (start, (544, random() % 150 + 11)),
+            Is::Balloon(Range{start, ..}) => (start, (rng.below(400) + 50, 358)),
+            Is::Copter(Range{start, ..}) => (start, (rng.below(256) + 272, -16)),
+            Is::Dart(Range{start, ..}) => (start, (544, rng.below(150) + 11)),
             Is::Steam{progress: Range{start, ..}} => (start, (0, 0)),
             _ => return None,
         };
@@ -111,21 +118,24 @@ impl object::Kind {
         Some(position.into())
     }
 
-    pub fn new(&self) -> Option<Object> {
+    pub fn new(&self, rng: &mut Rng) -> Option<Object> {
         let mut kind = self.clone();
-        let position = kind.reset(random() % 60 + 30)?;
+        let position = kind.reset(rng.below(60) + 30, rng)?;
         Some(Object{kind, position})
     }
 }
 
-fn random() -> i16 {
-	use std::sync::LazyLock;
-	use random::Source;
-	static mut RAND: LazyLock<std::cell::RefCell<random::Default>> = LazyLock::new(|| std::cell::RefCell::new(random::default(
-		match std::time::SystemTime::UNIX_EPOCH.elapsed() {
-			Ok(length) => length,
-			Err(wrong) => wrong.duration(),
-		}.as_secs()
-	)));
-    unsafe { RAND.borrow_mut().read::<i16>() } 
-}
\ No newline at end of file
+#[cfg(feature = "serde")]
+pub(super) mod bounce {
+    use super::Motion;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(motion: &Motion, serializer: S) -> Result<S::Ok, S::Error> {
+        ((-motion.limit.start >> 5) as u16).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Motion, D::Error> {
+        let height = u16::deserialize(deserializer)?;
+        Ok(Motion::new(-((height as i16) << 5), 0, 12))
+    }
+}
@@ -7,6 +7,8 @@ use std::num::NonZero;
 mod motion;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Id(pub NonZero<usize>);
 
@@ -42,12 +44,14 @@ impl From<self::Id> for usize {
 } */
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Duct {
     Blow(u16), 
     Travel(Option<room::Id>),
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind {
     Table{width: NonZero<u16>},
     Shelf{width: NonZero<u16>},
@@ -59,6 +63,8 @@ pub enum Kind {
     Dart(Interval),
     Copter(Interval),
     Balloon(Interval),
+    Downed(Box<Kind>),
+    Band(Side),
 
     FloorVent{height: u16},
     CeilingVent{height: u16},
@@ -81,11 +87,11 @@ pub enum Kind {
     Shredder{ready: bool},
     Guitar,
     
-    Drip{range: u16},
+    Drip{range: u16, delay: u16},
     Drop(Motion),
     Toaster{range: u16, delay: u16},
     Toast(Motion, i16),
-    Ball(Motion),
+    Ball(#[cfg_attr(feature = "serde", serde(with = "motion::bounce"))] Motion),
     Fishbowl{range: u16, delay: u16},
     Fish(Motion),
     Teakettle{delay: u16},
@@ -118,7 +124,8 @@ impl Kind {
             Is::Switch(..) | Is::Lights | Is::Thermostat |
             Is::Outlet{..} | Is::Shredder{..} | Is::Obstacle(..) | Is::Cabinet(..) |
             Is::Dart(..) | Is::Copter(..) | Is::Balloon(..) | Is::Flame | 
-            Is::Toast(..) | Is::Fish(..) | Is::Ball(..)
+            Is::Toast(..) | Is::Fish(..) | Is::Ball(..) |
+            Is::Downed(..) | Is::Band(..)
                 => (Span::Center, Rise::Center),
             Is::Fan{faces, ..} 
                 => (match faces {Side::Left => Span::Right, Side::Right => Span::Left}, Rise::Center),
@@ -139,6 +146,7 @@ impl Kind {
 
 #[disclose]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Object {
     kind: Kind,
     position: Position,
@@ -148,7 +156,8 @@ impl Object {
     pub fn is_animated(&self) -> bool {
         match self.kind {
             Kind::Ball(..) | Kind::Balloon(..) | Kind::Copter(..) | Kind::Dart(..) | Kind::Drop(..) |
-            Kind::Fish(..) | Kind::Outlet{..} | Kind::Grease{..} | Kind::Steam{..} | Kind::Toast(..)
+            Kind::Fish(..) | Kind::Outlet{..} | Kind::Grease{..} | Kind::Steam{..} | Kind::Toast(..) |
+            Kind::Downed(..) | Kind::Band(..)
                 => true,
             _ => false,
         }
@@ -190,6 +199,8 @@ impl Object {
             Kind::Grease {ready: false, progress: Range{start, ..}} if start > 0 => Size::new(start as u16, 2),
             Kind::Dart(..) => const{ Size::new(64, 22) },
             Kind::Ball(..) | Kind::Copter(..) | Kind::Balloon(..) => const{ Size::new(32, 32) },
+            Kind::Downed(ref enemy) => if let Kind::Dart(..) = **enemy { const{ Size::new(64, 22) } } else { const{ Size::new(32, 32) } },
+            Kind::Band(..) => const{ Size::new(16, 7) },
             Kind::Drop(..) => const{ Size::new(16, 14) },
 			Kind::Flame => const{ Size::new(11, 12) },
             Kind::Toast(..) => const{ Size::new(32, 31) },
@@ -221,6 +232,10 @@ impl Object {
         }
     }
 
+    pub fn is_enemy(&self) -> bool {
+        matches!(self.kind, Kind::Balloon(..) | Kind::Copter(..) | Kind::Dart(..) | Kind::Downed(..))
+    }
+
     pub fn is_dynamic(&self) -> bool {
         match self.kind {
             Kind::Clock(_) |
@@ -234,6 +249,8 @@ impl Object {
             Kind::Balloon(..) |
             Kind::Copter(..) |
             Kind::Dart(..) |
+            Kind::Downed(..) |
+            Kind::Band(..) |
             Kind::Flame |
             Kind::Drop(..) |
             Kind::Outlet{..} |
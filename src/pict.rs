@@ -0,0 +1,180 @@
+use std::fmt::Display;
+
+use super::Fork;
+
+pub const SHEET_WIDTH: usize = 512;
+pub const SHEET_HEIGHT: usize = 598;
+
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+#[disclose]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    bits: Vec<bool>,
+}
+
+#[derive(Debug)]
+pub enum PictError {
+    Missing(i16),
+    NotVersion1,
+    Truncated,
+    Opcode(u8),
+    Mode(u16),
+}
+
+impl Display for PictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(id) => write!(f, "no PICT {id}"),
+            Self::NotVersion1 => write!(f, "picture isn't a version 1 PICT"),
+            Self::Truncated => write!(f, "picture ends in the middle of an opcode"),
+            Self::Opcode(op) => write!(f, "opcode {op:#04x} isn't one a 1-bit picture uses"),
+            Self::Mode(mode) => write!(f, "transfer mode {mode} isn't copy or or"),
+        }
+    }
+}
+
+impl std::error::Error for PictError {}
+
+fn word(bytes: &[u8], at: usize) -> Result<u16, PictError> {
+    Ok(u16::from_be_bytes(*bytes.get(at..).and_then(|rest| rest.first_chunk()).ok_or(PictError::Truncated)?))
+}
+
+fn rect(bytes: &[u8], at: usize) -> Result<[i32; 4], PictError> {
+    Ok([word(bytes, at)? as i16 as i32, word(bytes, at + 2)? as i16 as i32, word(bytes, at + 4)? as i16 as i32, word(bytes, at + 6)? as i16 as i32])
+}
+
+// Inside Macintosh: Imaging With QuickDraw, A-8: PackBits, a count under 128 copies that many plus one bytes, over 128 repeats the next byte 257 minus it times.
+fn unpack(packed: &[u8], len: usize) -> Result<Vec<u8>, PictError> {
+    let mut bytes = packed.iter().copied();
+    let mut out = Vec::with_capacity(len);
+    while out.len() < len {
+        match bytes.next().ok_or(PictError::Truncated)? {
+            128 => (),
+            run @ ..128 => for _ in 0..=run { out.push(bytes.next().ok_or(PictError::Truncated)?) },
+            repeat => out.extend(std::iter::repeat_n(bytes.next().ok_or(PictError::Truncated)?, 257 - repeat as usize)),
+        }
+    }
+    Ok(out)
+}
+
+impl Bitmap {
+    // Inside Macintosh: Imaging With QuickDraw, A-22: a version 1 picture is its size and frame, then byte opcodes until 0xFF.
+    pub fn from_pict(pict: &[u8]) -> Result<Self, PictError> {
+        let [top, left, bottom, right] = rect(pict, 2)?;
+        let (width, height) = ((right - left).max(0) as usize, (bottom - top).max(0) as usize);
+        let mut bits = vec![false; width * height];
+        if pict.get(10..12) != Some(&[0x11, 0x01]) { return Err(PictError::NotVersion1) }
+        let mut at = 12;
+        loop {
+            let op = *pict.get(at).ok_or(PictError::Truncated)?;
+            at += 1;
+            match op {
+                0xFF => break,
+                0x00 | 0x1E => (),
+                0xA0 => at += 2,
+                0xA1 => at += 4 + word(pict, at + 2)? as usize,
+                0x01 => at += word(pict, at)? as usize,
+                0x90 | 0x91 | 0x98 | 0x99 => {
+                    let row_bytes = (word(pict, at)? & 0x7FFF) as usize;
+                    let [bounds_top, bounds_left, bounds_bottom, _] = rect(pict, at + 2)?;
+                    let [source_top, source_left, source_bottom, source_right] = rect(pict, at + 10)?;
+                    let [target_top, target_left, ..] = rect(pict, at + 18)?;
+                    let mode = word(pict, at + 26)?;
+                    if mode > 1 { return Err(PictError::Mode(mode)) }
+                    at += 28;
+                    if matches!(op, 0x91 | 0x99) { at += word(pict, at)? as usize }
+                    let mut rows = Vec::new();
+                    for _ in bounds_top..bounds_bottom {
+                        if matches!(op, 0x90 | 0x91) || row_bytes < 8 {
+                            rows.push(pict.get(at..at + row_bytes).ok_or(PictError::Truncated)?.to_vec());
+                            at += row_bytes;
+                        } else {
+                            let (packed, count) = if row_bytes > 250 { (word(pict, at)? as usize, 2) } else { (*pict.get(at).ok_or(PictError::Truncated)? as usize, 1) };
+                            rows.push(unpack(pict.get(at + count..at + count + packed).ok_or(PictError::Truncated)?, row_bytes)?);
+                            at += count + packed;
+                        }
+                    }
+                    for y in 0..source_bottom - source_top {
+                        for x in 0..source_right - source_left {
+                            let (from_x, from_y) = ((source_left - bounds_left + x) as usize, (source_top - bounds_top + y) as usize);
+                            let bit = rows.get(from_y).and_then(|row| row.get(from_x >> 3)).is_some_and(|byte| byte >> (7 - (from_x & 7)) & 1 == 1);
+                            let (to_x, to_y) = (target_left - left + x, target_top - top + y);
+                            if (0..width as i32).contains(&to_x) && (0..height as i32).contains(&to_y) {
+                                let pixel = &mut bits[to_y as usize * width + to_x as usize];
+                                *pixel = bit || (mode == 1 && *pixel);
+                            }
+                        }
+                    }
+                }
+                op => return Err(PictError::Opcode(op)),
+            }
+        }
+        Ok(Self { width, height, bits })
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool { self.bits[y * self.width + x] }
+
+    pub fn rgba(&self) -> Vec<u8> {
+        self.bits.iter().flat_map(|&black| if black { BLACK } else { WHITE }).collect()
+    }
+}
+
+pub fn picture(art: &Fork, id: i16) -> Result<Bitmap, PictError> {
+    Bitmap::from_pict(&art.get("PICT", id).ok_or(PictError::Missing(id))?.data)
+}
+
+// The B&W sprite sheet, laid out like the colour one: PICT 128 through its mask 129 on top, clear where the mask is, then the two staircases below.
+pub fn sprite_sheet(art: &Fork) -> Result<Vec<u8>, PictError> {
+    let (sprites, mask) = (picture(art, 128)?, picture(art, 129)?);
+    let mut sheet = vec![0; SHEET_WIDTH * SHEET_HEIGHT * 4];
+    for y in 0..mask.height.min(SHEET_HEIGHT) {
+        for x in 0..mask.width.min(SHEET_WIDTH) {
+            // The fading glider frames are only drawn in the mask, so they fade out solid black.
+            let black = sprites.get(x, y) || (x >= 414 && (97..244).contains(&y));
+            if mask.get(x, y) { sheet[(y * SHEET_WIDTH + x) * 4..][..4].copy_from_slice(if black { &BLACK } else { &WHITE }) }
+        }
+    }
+    for (id, left) in [(198, 1), (199, 163)] {
+        let stairs = picture(art, id)?;
+        for y in 0..stairs.height.min(SHEET_HEIGHT - 343) {
+            for x in 0..stairs.width.min(SHEET_WIDTH - left) {
+                sheet[((343 + y) * SHEET_WIDTH + left + x) * 4..][..4].copy_from_slice(if stairs.get(x, y) { &BLACK } else { &WHITE });
+            }
+        }
+    }
+    Ok(sheet)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::*;
+
+    fn digest(bytes: impl IntoIterator<Item = u8>) -> u32 {
+        bytes.into_iter().fold(0x811C_9DC5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x0100_0193))
+    }
+
+    #[test]
+    fn decode_bw_art() {
+        let art = Fork::open(Path::new(env!("CARGO_MANIFEST_DIR")).join("Glider_405").join("B&W Art.bin")).unwrap();
+        let rooms = [0x3747778B, 0x43BB1FE0, 0xE55413DD, 0x24229482, 0x3249C5AF, 0xCCA32305, 0x0FE67F17, 0x73AA1FA0, 0x18E31C6E, 0x72B0A07A];
+        for (id, expected) in (200..).zip(rooms) {
+            let room = picture(&art, id).unwrap();
+            assert_eq!((room.width, room.height), (512, 342));
+            assert_eq!(digest(room.bits.iter().map(|&black| black as u8)), expected, "PICT {id}");
+        }
+        for id in [198, 199] {
+            let stairs = picture(&art, id).unwrap();
+            assert_eq!((stairs.width, stairs.height), (161, 254));
+        }
+        assert_eq!(digest(sprite_sheet(&art).unwrap()), 0x63E9D094);
+        assert!(matches!(picture(&art, 300), Err(PictError::Missing(300))));
+        let pict = &art.get("PICT", 200).unwrap().data;
+        assert!(matches!(Bitmap::from_pict(&pict[..pict.len() / 2]), Err(PictError::Truncated)));
+    }
+}
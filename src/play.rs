@@ -1,11 +1,14 @@
-use crate::{Environment, Position, Reference, Displacement, Size, Bounds, Update, Vertical, cart::{Rise, Span}, prelude::{Blow, Travel}};
+use crate::{Changes, Environment, Position, Reference, Displacement, Rng, Size, Bounds, Update, Vertical, cart::{Rise, Span}, prelude::{Blow, Travel}};
 
 use super::{Input, Outcome, object::{self, Object, Kind, Motion}, room::{self, On, Room}, Side};
 use std::{iter::{from_fn, once}, num::NonZero, ops::{Index, IndexMut, Range}};
 
-const MAX_THRUST: i16 = 5;
+pub const MAX_THRUST: i16 = 5;
+const ZOOM_THRUST: i16 = 16;
+pub const DEFAULT_DRAIN: u16 = 1;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Entrance {
     Spawn(Side),
     Flying(Side, u16),
@@ -144,10 +147,17 @@ pub struct Play {
     motion: Displacement,
     on: On,
     now: Option<State>,
+    energy: u16,
+    drain: u16,
+    bands: u16,
+    rng: Rng,
+    changes: Changes,
+    cast: Vec<(usize, Object)>,
 }
 
 impl Room {
-    pub fn start(&self, from: Entrance) -> Play {
+    pub fn start(&self, from: Entrance, seed: u64) -> Play {
+        let mut rng = Rng::new(seed);
         eprintln!("{}", self.name);
         for o in &self.objects {
         	eprintln!("{o:?}");
@@ -155,12 +165,13 @@ impl Room {
         eprintln!("{:?}", self.environs);
         let mut objects = Vec::from_iter( 
             once(None)
-            .chain(self.objects.iter().map(|object| (!object.is_cosmetic()).then(|| object.clone()) ))
+            .chain(self.objects.iter().map(|object| (!object.is_cosmetic()).then(|| object.launch()) ))
         );
-        self.objects.iter().filter_map(|host| host.effect().map(|spawn| Some(spawn))).collect_into(&mut objects);
+        self.objects.iter().filter_map(|host| host.effect(&mut rng).map(|spawn| Some(spawn))).collect_into(&mut objects);
         self.animate.as_ref()
-        .map(|(count, kind)| from_fn(move || Some(kind.new())).take(count.get() as usize))
+        .map(|(count, kind)| from_fn(|| Some(kind.new(&mut rng))).take(count.get() as usize))
         .into_iter().flatten().collect_into(&mut objects);
+        let cast = objects.iter().enumerate().filter_map(|(i, o)| Some((i, o.clone().filter(Object::is_enemy)?))).collect();
                 
         let mut this = Play {
             walls: &BOUNDS[self.walls()],
@@ -172,6 +183,12 @@ impl Room {
             motion: Displacement::default(),
             on: self.environs,
             now: from.action(),
+            energy: 0,
+            drain: DEFAULT_DRAIN,
+            bands: 0,
+            rng,
+            changes: Changes::default(),
+            cast,
         };
         this.reset(from);
         this
@@ -179,13 +196,19 @@ impl Room {
 }
 
 impl Object {
-    fn effect(&self) -> Option<Object> {
+    fn launch(&self) -> Object {
+        let mut this = self.clone();
+        if let Kind::Ball(ref mut bounce) = this.kind { bounce.reset() }
+        this
+    }
+
+    fn effect(&self, rng: &mut Rng) -> Option<Object> {
         Some(match self.kind {
             Kind::Candle {..} => Object{
                 kind: Kind::Flame,
                 position: self.position - (3, 27),
             },
-            Kind::Drip { range } => Object{
+            Kind::Drip { range, .. } => Object{
                 kind: Kind::Drop(Motion::new(-7, (range as i16) << 5 + 1, 12)),
                 position: self.position,
             },
@@ -202,7 +225,7 @@ impl Object {
                 position: self.position - (0, 18)
             },
             Kind::Teakettle { delay } => {
-                let mut steam = Kind::Steam { progress: -10..(delay as i16) }.new()?;
+                let mut steam = Kind::Steam { progress: -10..(delay as i16) }.new(rng)?;
                 steam.position = (const{Size::new(41, 30).unwrap()} / (Span::Center, Rise::Bottom) << *self.position) * (Span::Left, Rise::Top);
                 if let Kind::Steam{progress: Range{start, ..}} = &mut steam.kind {
                     *start += 20;
@@ -325,10 +348,12 @@ impl Play {
             }
             Change::Heat => {
                 self.on.air = true;
+                self.changes.heated = true;
                 Update::Air
             }
             Change::Light => {
                 self.on.lights = true;
+                self.changes.lit = true;
                 Update::Lights
             }
             Change::Spill => {
@@ -336,6 +361,7 @@ impl Play {
                     Some(Object{kind: Kind::Grease{ready, ..}, ..}) if *ready == true => {*ready = false;}
                     _ => return None
                 }
+                self.changes.spilled.push(source.get() as u16);
                 Update::Start(Environment::Grease, Some(source))
             }
             Change::Toggle(id) => {
@@ -345,7 +371,7 @@ impl Play {
                 match &mut self.objects[id.get()] {
                     Some(Object{kind: Kind::Shredder { ready }, ..}) |
                     Some(Object{kind: Kind::Fan { ready, .. }, ..}) 
-                        => *ready = !*ready,
+                        => { *ready = !*ready; self.changes.toggle(id.get() as u16) }
                     _ => ()
                 };
                 Update::Start(Environment::Switch, None)
@@ -371,10 +397,11 @@ impl Play {
         };
 
         let (mut motion, collision) = control.unwrap_or_else(|| {
-            let mut motion = Displacement::new(0, 3);
+            let (mut thrust, mut zooming) = (0, false);
             for action in actions {
                 match action {
-                    Input::Go(direction) => *motion.x_mut() += *direction * MAX_THRUST,
+                    Input::Go(direction) => thrust += *direction * MAX_THRUST,
+                    Input::Steer(steer) => thrust += (*steer).clamp(-MAX_THRUST, MAX_THRUST),
                     Input::Flip => {
                         let turn = State::Turning(-self.facing, 0..11);
                         match &self.now {
@@ -383,16 +410,21 @@ impl Play {
                         };
                         signal.get_or_insert_with(|| vec![Update::Turn(-self.facing)]);
                     },
-                    _ => ()
+                    Input::Shoot => if let Some(update) = self.shoot() { signal.get_or_insert_with(Vec::new).push(update) },
+                    Input::Zoom => zooming = true,
                 };
             }
-            (motion, true)
+            // Keys and mouse together steer no harder than either alone; a zoom boosts that once, however the inputs were ordered.
+            let mut thrust = thrust.clamp(-MAX_THRUST, MAX_THRUST);
+            if let Some(updates) = zooming.then(|| self.zoom(&mut thrust)).flatten() { signal.get_or_insert_with(Vec::new).extend(updates) }
+            (Displacement::new(thrust, 3), true)
         });
         for animated in &mut self.objects {
             let Some(animated) = animated else {continue};
-            animated.advance()
+            animated.advance(&mut self.rng)
         }
-        let events = if collision {
+        let mut struck = self.fly_band();
+        let mut events = if collision {
             if let Ok(touch) = Bounds::try_from(PLAYER_SIZE / (Span::Center, Rise::Center) << *self.player) {
                 let objects = 
                     self.objects.iter().enumerate().filter_map(|(i, o)| Some((object::Id::try_from(i).ok()?, o.as_ref()?)) )
@@ -417,6 +449,7 @@ impl Play {
         } else {
             signal
         }; 
+        if !struck.is_empty() { events.get_or_insert_with(Vec::new).append(&mut struck) }
         self.motion = motion;
         self.player += <(i16, i16)>::from(motion);
         if let Some((room::Id(to), out)) = match self.player.x() {..-12 => Some(Side::Left), 489.. => Some(Side::Right), _ => None}.and_then(|s| self.exits[s].zip(Some(s))) {
@@ -425,12 +458,49 @@ impl Play {
         Outcome::Continue(events)
     }
 
+    fn zoom(&mut self, thrust: &mut i16) -> Option<[Update; 2]> {
+        if self.energy == 0 { return None }
+        self.energy = self.energy.saturating_sub(self.drain);
+        *thrust = (*thrust + self.facing * ZOOM_THRUST).clamp(-ZOOM_THRUST, ZOOM_THRUST);
+        Some([Update::Zoom, Update::Energy(self.energy, None)])
+    }
+
+    fn in_flight(&self) -> bool { matches!(self.objects.last(), Some(Some(Object{kind: Kind::Band(..), ..}))) }
+
+    fn shoot(&mut self) -> Option<Update> {
+        if self.in_flight() || self.bands == 0 { return None }
+        self.bands -= 1;
+        self.objects.push(Some(Object{kind: Kind::Band(self.facing), position: self.player + (self.facing * 32, 0)}));
+        Some(Update::Shoot)
+    }
+
+    fn fly_band(&mut self) -> Vec<Update> {
+        let Some(Some(band)) = self.objects.last().filter(|_| self.in_flight()) else { return Vec::new() };
+        let Some(flight) = band.active_area() else { return Vec::new() };
+        let walled = self.walls.iter().filter(|wall| matches!(wall.kind, Kind::Wall(..))).any(|wall| (wall.active_area() & flight).is_some());
+        if walled || (flight & room::BOUNDS).is_none() {
+            self.objects.pop();
+            return Vec::new()
+        }
+        let mut struck = Vec::new();
+        for (index, slot) in self.objects.iter_mut().enumerate() {
+            let Some(enemy) = slot else { continue };
+            let points = match enemy.kind { Kind::Dart(..) => 300, Kind::Copter(..) => 200, Kind::Balloon(..) => 100, _ => continue };
+            if (enemy.active_area() & flight).is_none() { continue }
+            enemy.kind = Kind::Downed(Box::new(enemy.kind.clone()));
+            struck.extend([Update::Pop(object::Id::from(index as u16)), Update::Score(points, enemy.position)]);
+        }
+        struck
+    }
+
     fn award(&mut self, id: object::Id) -> Option<Update> {
         let object = self.objects[id.get()].take()?;
+        self.changes.taken.push(id.get() as u16);
         let position = object.position;
         let ping = match object.kind {
             Kind::Battery(value) => {
-                Update::Energy(value, position)
+                self.energy = self.energy.saturating_add(value as u16);
+                Update::Energy(self.energy, Some(position))
             }
             Kind::Bonus(value, _) |
             Kind::Clock(value) 
@@ -441,6 +511,7 @@ impl Play {
                 Update::Life(value, position)
             }
             Kind::RubberBands(count) => {
+                self.bands = self.bands.saturating_add(count as u16);
                 Update::Bands(count, position)
             }
             _ => return None
@@ -448,6 +519,35 @@ impl Play {
         Some(ping)
     }
 
+    pub fn charge(&mut self, energy: u16) { self.energy = energy }
+    pub fn set_drain(&mut self, drain: u16) { self.drain = drain }
+    pub fn energy(&self) -> u16 { self.energy }
+    pub fn arm(&mut self, bands: u16) { self.bands = bands }
+    pub fn bands(&self) -> u16 { self.bands }
+
+    pub fn seed(&self) -> u64 { self.rng.seed() }
+
+    pub fn changes(&self) -> &Changes { &self.changes }
+
+    pub fn restore(&mut self, changes: &Changes) {
+        self.on.lights |= changes.lit;
+        self.on.air |= changes.heated;
+        for &id in &changes.taken {
+            if let Some(slot) = self.objects.get_mut(id as usize) { *slot = None }
+        }
+        for &id in &changes.toggled {
+            match self.objects.get_mut(id as usize) {
+                Some(Some(Object{kind: Kind::Shredder { ready }, ..})) |
+                Some(Some(Object{kind: Kind::Fan { ready, .. }, ..})) => *ready = !*ready,
+                _ => ()
+            }
+        }
+        for &id in &changes.spilled {
+            if let Some(Some(Object{kind: Kind::Grease{ready, ..}, ..})) = self.objects.get_mut(id as usize) { *ready = false }
+        }
+        self.changes = changes.clone();
+    }
+
     pub fn dark(&self) -> bool { !self.on.lights }
     pub fn cold(&self) -> bool { !self.on.air }
 
@@ -463,7 +563,7 @@ impl Play {
             .filter_map(|(id, o)| { 
                 if self.dark() {
                     match o.kind {
-                        Kind::Lights | Kind::Balloon(..) | Kind::Dart(..) | Kind::Copter(..) | 
+                        Kind::Lights | Kind::Balloon(..) | Kind::Dart(..) | Kind::Copter(..) | Kind::Downed(..) | Kind::Band(..) |
                         Kind::Outlet{progress: Range{start: ..=0, ..}, ..} | Kind::Drop(Motion{limit: Range{start: 1.., ..}, ..})
                             => (),
                         _ => return None,
@@ -507,6 +607,13 @@ impl Play {
             Entrance::Up => ((self.entrance(from) + 8, room::VERT_CEILING as i16 + 10), Side::Right)
         }
     }
+
+    // Coming back from another room keeps everything in `changes` but sends the enemies round again, as SetRoomState did.
+    pub fn enter(&mut self, at: Entrance) {
+        for (i, enemy) in &self.cast { self.objects[*i] = Some(enemy.clone()) }
+        self.reset(at);
+    }
+
     pub fn reset(&mut self, at: Entrance) {
         let ((x, y), facing) = self.enter_at(at);
         if !matches!(at, Entrance::Air) {
@@ -515,6 +622,7 @@ impl Play {
         self.player = Reference::new(x, y);
         self.motion = Displacement::default();
         self.now = at.action();
+        if self.in_flight() { self.objects.pop(); }
     }
 
     pub fn debug_zones<'this>(&'this self) -> impl Iterator<Item=Bounds> + 'this {
@@ -541,4 +649,170 @@ impl<'a> IntoIterator for &'a Play {
     fn into_iter(self) -> Self::IntoIter {
         self.objects.iter().filter_map(Option::as_ref)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Position, room::Exits};
+
+    fn room(objects: Vec<Object>) -> Room {
+        Room {
+            name: "Target Practice".into(),
+            back_pict_id: 200,
+            tile_order: [0; 8],
+            exits: Exits{left: None, right: None},
+            animate: None,
+            environs: On{air: true, lights: true},
+            objects,
+        }
+    }
+
+    fn band(play: &Play) -> Option<&Object> {
+        play.objects.last()?.as_ref().filter(|object| matches!(object.kind, Kind::Band(..)))
+    }
+
+    #[test]
+    fn shoot_down_balloon() {
+        let balloon = Object{kind: Kind::Balloon(0..1000), position: Position::new(200, 100)};
+        let mut play = room(vec![balloon]).start(Entrance::Flying(Side::Left, 100), 1);
+        play.arm(1);
+        let id = object::Id::from(1);
+        let mut hits = Vec::new();
+        for frame in 0..20 {
+            let Outcome::Continue(updates) = play.frame(if frame < 2 {&[Input::Shoot]} else {&[]}) else { panic!("glider lost") };
+            let updates = updates.unwrap_or_default();
+            assert_eq!(updates.iter().filter(|update| matches!(update, Update::Shoot)).count(), (frame == 0) as usize);
+            hits.extend(updates.into_iter().filter(|update| matches!(update, Update::Pop(..) | Update::Score(..))));
+            if !hits.is_empty() { break }
+        }
+        assert!(matches!(hits[..], [Update::Pop(popped), Update::Score(100, _)] if popped == id));
+        assert!(matches!(play[id].kind, Kind::Downed(ref enemy) if matches!(**enemy, Kind::Balloon(..))));
+        let height = play[id].position.y();
+        play.frame(&[]);
+        assert_eq!(play[id].position.y(), height + 12);
+    }
+
+    #[test]
+    fn fly_through_downed_enemy() {
+        let balloon = Object{kind: Kind::Balloon(0..1000), position: Position::new(200, 100)};
+        let mut play = room(vec![balloon]).start(Entrance::Flying(Side::Left, 100), 1);
+        play.arm(1);
+        let id = object::Id::from(1);
+        for frame in 0..20 {
+            play.frame(if frame == 0 {&[Input::Shoot]} else {&[]});
+            if matches!(play[id].kind, Kind::Downed(..)) { break }
+        }
+        assert!(matches!(play[id].kind, Kind::Downed(..)));
+        let downed = play[id].position;
+        play.player = Reference::new(downed.x(), downed.y() + 12);
+        assert!(play[id].active_area().is_some_and(|area| (area & Bounds::try_from((PLAYER_SIZE / (Span::Center, Rise::Center)) << *play.player).unwrap()).is_some()));
+        for _ in 0..20 {
+            assert!(matches!(play.frame(&[]), Outcome::Continue(..)));
+        }
+    }
+
+    #[test]
+    fn zoom_drains_battery() {
+        let mut play = room(Vec::new()).start(Entrance::Flying(Side::Left, 100), 1);
+        play.frame(&[]);
+        let x = play.player.x();
+        let Outcome::Continue(updates) = play.frame(&[Input::Zoom]) else { panic!("glider lost") };
+        assert!(updates.unwrap_or_default().is_empty());
+        assert_eq!(play.player.x(), x);
+
+        play.charge(5);
+        play.set_drain(2);
+        let mut left = Vec::new();
+        for _ in 0..4 {
+            let x = play.player.x();
+            let Outcome::Continue(updates) = play.frame(&[Input::Go(Side::Right), Input::Zoom]) else { panic!("glider lost") };
+            let drained = left.len();
+            left.extend(updates.unwrap_or_default().into_iter().filter_map(|update| match update { Update::Energy(charge, None) => Some(charge), _ => None }));
+            assert_eq!(play.player.x() - x, if left.len() > drained { ZOOM_THRUST } else { MAX_THRUST });
+        }
+        assert_eq!(left, [3, 1, 0]);
+        assert_eq!(play.energy(), 0);
+    }
+
+    #[test]
+    fn zoom_ignores_input_order() {
+        for inputs in [[Input::Go(Side::Right), Input::Steer(4), Input::Zoom], [Input::Zoom, Input::Steer(4), Input::Go(Side::Right)]] {
+            let mut play = room(Vec::new()).start(Entrance::Flying(Side::Left, 100), 1);
+            play.frame(&[]);
+            play.charge(5);
+            let x = play.player.x();
+            play.frame(&inputs);
+            assert_eq!(play.player.x() - x, ZOOM_THRUST);
+            play.frame(&[Input::Go(Side::Right), Input::Steer(4)]);
+            assert_eq!(play.player.x() - x, ZOOM_THRUST + MAX_THRUST);
+        }
+    }
+
+    #[test]
+    fn replay_from_seed() {
+        let mut copters = room(Vec::new());
+        copters.animate = NonZero::new(3).zip(Some(Kind::Copter(0..0)));
+        let run = |seed| {
+            let mut play = copters.start(Entrance::Flying(Side::Left, 100), seed);
+            assert_eq!(play.seed(), seed);
+            (0..200).map(|_| { play.frame(&[]); format!("{:?}", play.objects) }).collect::<Vec<_>>()
+        };
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+    }
+
+    #[test]
+    fn revisit_room() {
+        let mut dark = room(vec![
+            Object{kind: Kind::Clock(500), position: Position::new(60, 100)},
+            Object{kind: Kind::Balloon(0..1000), position: Position::new(250, 130)},
+            Object{kind: Kind::Fan{faces: Side::Left, range: 10, ready: false}, position: Position::new(450, 300)},
+            Object{kind: Kind::Switch(object::Id::from(3), 0..0), position: Position::new(400, 300)},
+            Object{kind: Kind::Grease{progress: -3..20, ready: true}, position: Position::new(300, 300)},
+        ]);
+        dark.environs.lights = false;
+        let mut play = dark.start(Entrance::Flying(Side::Left, 100), 1);
+        play.arm(1);
+        let (balloon, fan, switch, grease) = (object::Id::from(2), object::Id::from(3), object::Id::from(4), object::Id::from(5));
+        let launch = play[balloon].position;
+        for frame in 0..30 {
+            let Outcome::Continue(..) = play.frame(if frame == 10 {&[Input::Shoot]} else {&[Input::Go(Side::Right)]}) else { panic!("glider lost") };
+        }
+        assert!(play.objects[1].is_none());
+        assert!(matches!(play[balloon].kind, Kind::Downed(..)));
+        for (source, change) in [(switch, Change::Light), (switch, Change::Toggle(fan)), (grease, Change::Spill)] {
+            play.apply(source, change);
+        }
+
+        play.reset(Entrance::default());
+        assert!(matches!(play[balloon].kind, Kind::Downed(..)));
+        play.enter(Entrance::default());
+        assert!(matches!(play[balloon].kind, Kind::Balloon(..)));
+        assert_eq!(play[balloon].position, launch);
+        assert!(play.objects[1].is_none());
+        assert_eq!(play.changes().taken, [1]);
+        assert!(!play.dark() && play.changes().lit);
+        assert!(matches!(play[fan].kind, Kind::Fan{ready: true, ..}));
+        assert!(matches!(play[grease].kind, Kind::Grease{ready: false, ..}));
+        assert_eq!((&play.changes().toggled[..], &play.changes().spilled[..]), (&[3][..], &[5][..]));
+    }
+
+    #[test]
+    fn band_stops_at_wall() {
+        let mut play = room(Vec::new()).start(Entrance::Flying(Side::Left, 100), 1);
+        play.arm(2);
+        play.frame(&[Input::Shoot]);
+        assert!(band(&play).is_some());
+        let mut flight = 0;
+        while band(&play).is_some() {
+            play.frame(&[]);
+            flight += 1;
+            assert!(flight < 32, "band never hit the wall");
+        }
+        assert!(band(&play).is_none());
+        play.frame(&[Input::Shoot]);
+        assert!(band(&play).is_some_and(|band| matches!(band.kind, Kind::Band(Side::Right))));
+        assert_eq!(play.bands(), 0);
+    }
+}
@@ -0,0 +1,205 @@
+use std::{fmt::Display, path::{Path, PathBuf}};
+
+use super::{Input, Side};
+
+pub const CONTROLS: [Input; 5] = [Input::Go(Side::Left), Input::Go(Side::Right), Input::Flip, Input::Shoot, Input::Zoom];
+const FILE: &str = "prefs.toml";
+
+#[disclose]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prefs {
+    keys: Vec<(Input, String)>,
+    buttons: Vec<(Input, String)>,
+    dead_zone: u8,
+    mouse: bool,
+    sensitivity: u8,
+    volume: u8,
+    house: Option<PathBuf>,
+    scale: u8,
+    fullscreen: bool,
+    integer_scale: bool,
+    monochrome: bool,
+}
+
+impl Default for Prefs {
+    fn default() -> Self {
+        Self{
+            keys: CONTROLS.iter().zip(["Left", "Right", "Up", "Space", "Down"]).map(|(&input, key)| (input, key.to_string())).collect(),
+            buttons: CONTROLS.iter().zip(["dpleft", "dpright", "a", "x", "b"]).map(|(&input, button)| (input, button.to_string())).collect(),
+            dead_zone: 25,
+            mouse: false,
+            sensitivity: 100,
+            volume: 100,
+            house: None,
+            scale: 1,
+            fullscreen: false,
+            integer_scale: true,
+            monochrome: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PrefsError {
+    Malformed{line: usize},
+    BadValue{line: usize, key: String},
+    Unreadable(std::io::Error),
+}
+
+impl Display for PrefsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed{line} => write!(f, "line {line} isn't a section or a key = value pair"),
+            Self::BadValue{line, key} => write!(f, "line {line} gives \"{key}\" a value it can't take"),
+            Self::Unreadable(err) => write!(f, "preferences could not be read ({err})"),
+        }
+    }
+}
+
+impl std::error::Error for PrefsError {}
+
+impl From<std::io::Error> for PrefsError {
+    fn from(value: std::io::Error) -> Self { Self::Unreadable(value) }
+}
+
+impl Input {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Input::Go(Side::Left) => "left",
+            Input::Go(Side::Right) => "right",
+            Input::Steer(..) => "steer",
+            Input::Flip => "flip",
+            Input::Shoot => "shoot",
+            Input::Zoom => "zoom",
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Self> { CONTROLS.into_iter().find(|input| input.name() == name) }
+}
+
+fn quoted(value: &str) -> Option<String> {
+    let mut chars = value.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut text = String::new();
+    while let Some(c) = chars.next() {
+        text.push(match c {
+            '\\' => chars.next()?,
+            '"' => return None,
+            c => c,
+        });
+    }
+    Some(text)
+}
+
+fn bind(list: &mut Vec<(Input, String)>, input: Input, name: String) {
+    match list.iter_mut().find(|(bound, _)| *bound == input) {
+        Some((_, old)) => *old = name,
+        None => list.push((input, name)),
+    }
+}
+
+fn bound(list: &[(Input, String)], input: Input) -> Option<&str> {
+    list.iter().find(|(bound, _)| *bound == input).map(|(_, name)| name.as_str())
+}
+
+fn quote(text: &str) -> String { format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"")) }
+
+impl Prefs {
+    pub fn key(&self, input: Input) -> Option<&str> { bound(&self.keys, input) }
+
+    pub fn button(&self, input: Input) -> Option<&str> { bound(&self.buttons, input) }
+
+    pub fn bind(&mut self, input: Input, key: impl Into<String>) { bind(&mut self.keys, input, key.into()) }
+
+    pub fn bind_button(&mut self, input: Input, button: impl Into<String>) { bind(&mut self.buttons, input, button.into()) }
+
+    pub fn parse(text: &str) -> Result<Self, PrefsError> {
+        let mut prefs = Self::default();
+        let mut section = String::new();
+        for (n, line) in text.lines().enumerate().map(|(n, line)| (n + 1, line.trim())) {
+            if line.is_empty() || line.starts_with('#') { continue }
+            if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                section = name.trim().to_string();
+                continue
+            }
+            let Some((key, value)) = line.split_once('=').map(|(key, value)| (key.trim(), value.trim())) else { return Err(PrefsError::Malformed{line: n}) };
+            let bad = || PrefsError::BadValue{line: n, key: key.to_string()};
+            match (section.as_str(), key) {
+                ("", "volume") => prefs.volume = value.parse().ok().filter(|volume| *volume <= 100).ok_or_else(bad)?,
+                ("", "dead_zone") => prefs.dead_zone = value.parse().ok().filter(|dead_zone| *dead_zone < 100).ok_or_else(bad)?,
+                ("", "mouse") => prefs.mouse = value.parse().map_err(|_| bad())?,
+                ("", "sensitivity") => prefs.sensitivity = value.parse().ok().filter(|sensitivity| *sensitivity > 0).ok_or_else(bad)?,
+                ("", "scale") => prefs.scale = value.parse().ok().filter(|scale| *scale > 0).ok_or_else(bad)?,
+                ("", "fullscreen") => prefs.fullscreen = value.parse().map_err(|_| bad())?,
+                ("", "integer_scale") => prefs.integer_scale = value.parse().map_err(|_| bad())?,
+                ("", "monochrome") => prefs.monochrome = value.parse().map_err(|_| bad())?,
+                ("", "house") => prefs.house = Some(quoted(value).ok_or_else(bad)?.into()),
+                ("keys", name) => if let Some(input) = Input::named(name) { prefs.bind(input, quoted(value).ok_or_else(bad)?) },
+                ("buttons", name) => if let Some(input) = Input::named(name) { prefs.bind_button(input, quoted(value).ok_or_else(bad)?) },
+                _ => (),
+            }
+        }
+        Ok(prefs)
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        let config = std::env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()).map(PathBuf::from)
+            .or_else(|| Some(PathBuf::from(std::env::var_os("HOME")?).join(".config")))?;
+        Some(config.join("glider").join(FILE))
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, PrefsError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        if let Some(folder) = path.as_ref().parent() { std::fs::create_dir_all(folder)? }
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl Display for Prefs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "volume = {}", self.volume)?;
+        writeln!(f, "scale = {}", self.scale)?;
+        writeln!(f, "fullscreen = {}", self.fullscreen)?;
+        writeln!(f, "integer_scale = {}", self.integer_scale)?;
+        writeln!(f, "monochrome = {}", self.monochrome)?;
+        writeln!(f, "dead_zone = {}", self.dead_zone)?;
+        writeln!(f, "mouse = {}", self.mouse)?;
+        writeln!(f, "sensitivity = {}", self.sensitivity)?;
+        if let Some(house) = &self.house { writeln!(f, "house = {}", quote(&house.to_string_lossy()))? }
+        writeln!(f, "\n[keys]")?;
+        self.keys.iter().try_for_each(|(input, key)| writeln!(f, "{} = {}", input.name(), quote(key)))?;
+        writeln!(f, "\n[buttons]")?;
+        self.buttons.iter().try_for_each(|(input, button)| writeln!(f, "{} = {}", input.name(), quote(button)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_preferences() {
+        let prefs = Prefs::parse("# mine\nvolume = 40\nmouse = true\ninteger_scale = false\nmonochrome = true\nhouse = \"Houses/Mad \\\"House\\\"\"\n\n[keys]\nflip = \"W\"\nshoot = \"Left Ctrl\"\nwarp = \"X\"\n[buttons]\nzoom = \"rightshoulder\"\n").unwrap();
+        assert_eq!((prefs.volume, prefs.scale, prefs.mouse, prefs.sensitivity), (40, 1, true, 100));
+        assert_eq!((prefs.fullscreen, prefs.integer_scale, prefs.monochrome), (false, false, true));
+        assert_eq!(prefs.house.as_deref(), Some(Path::new("Houses/Mad \"House\"")));
+        assert_eq!((prefs.key(Input::Flip), prefs.key(Input::Shoot), prefs.key(Input::Go(Side::Left))), (Some("W"), Some("Left Ctrl"), Some("Left")));
+        assert_eq!((prefs.button(Input::Zoom), prefs.button(Input::Flip), prefs.dead_zone), (Some("rightshoulder"), Some("a"), 25));
+        assert_eq!(Prefs::parse(&prefs.to_string()).unwrap(), prefs);
+        assert_eq!(Prefs::parse(&Prefs::default().to_string()).unwrap(), Prefs::default());
+
+        assert!(matches!(Prefs::parse("volume 40"), Err(PrefsError::Malformed{line: 1})));
+        assert!(matches!(Prefs::parse("\nvolume = 140"), Err(PrefsError::BadValue{line: 2, ..})));
+        assert!(matches!(Prefs::parse("[keys]\nzoom = Down"), Err(PrefsError::BadValue{line: 2, ..})));
+        assert!(matches!(Prefs::parse("dead_zone = 100"), Err(PrefsError::BadValue{line: 1, ..})));
+        assert!(matches!(Prefs::parse("mouse = yes"), Err(PrefsError::BadValue{line: 1, ..})));
+        assert!(matches!(Prefs::parse("sensitivity = 0"), Err(PrefsError::BadValue{line: 1, ..})));
+        assert!(CONTROLS.iter().all(|input| Input::named(input.name()) == Some(*input)));
+    }
+}
@@ -0,0 +1,325 @@
+use std::{fmt::Display, num::NonZero, path::Path};
+
+use super::{Entrance, Input, MAX_THRUST, Outcome, Play, Room, Side, Status};
+
+const MAGIC: &[u8; 4] = b"GLRP";
+const VERSION: u8 = 1;
+const ENTER: u8 = 0xFF;
+const STEER: u8 = 0x10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mark {
+    Enter(NonZero<u16>, Entrance),
+    Frames(u32, Vec<Input>),
+}
+
+#[disclose]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    seed: u64,
+    marks: Vec<Mark>,
+    checksum: Option<u64>,
+}
+
+#[disclose]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ending {
+    status: Status,
+    room: NonZero<u16>,
+    player: (i16, i16),
+    checksum: u64,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    NotAReplay,
+    Version(u8),
+    Truncated,
+    BadInput(u8),
+    BadEntrance(u8),
+    UnknownRoom(NonZero<u16>),
+    NoRoom,
+    Unreadable(std::io::Error),
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotAReplay => write!(f, "file isn't a glider replay"),
+            Self::Version(version) => write!(f, "replay version {version} isn't supported; expected {VERSION}"),
+            Self::Truncated => write!(f, "replay ends early"),
+            Self::BadInput(code) => write!(f, "replay holds unknown input code {code}"),
+            Self::BadEntrance(code) => write!(f, "replay holds unknown entrance code {code}"),
+            Self::UnknownRoom(id) => write!(f, "replay enters room {id}, which isn't in this house"),
+            Self::NoRoom => write!(f, "replay plays frames before entering any room"),
+            Self::Unreadable(err) => write!(f, "replay file could not be read ({err})"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<std::io::Error> for ReplayError {
+    fn from(value: std::io::Error) -> Self { Self::Unreadable(value) }
+}
+
+impl Ending {
+    pub fn new(status: Status, room: NonZero<u16>, player: (i16, i16)) -> Self {
+        let mut bytes = Vec::with_capacity(16);
+        bytes.extend(status.score.to_be_bytes());
+        [status.mortals, status.energy, status.bands, room.get()].into_iter().for_each(|n| bytes.extend(n.to_be_bytes()));
+        [player.0, player.1].into_iter().for_each(|n| bytes.extend(n.to_be_bytes()));
+        let checksum = bytes.into_iter().fold(0xCBF2_9CE4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3));
+        Self{status, room, player, checksum}
+    }
+}
+
+impl Input {
+    fn code(&self) -> u8 {
+        match self {
+            Input::Go(Side::Left) => 0,
+            Input::Go(Side::Right) => 1,
+            Input::Flip => 2,
+            Input::Shoot => 3,
+            Input::Zoom => 4,
+            Input::Steer(thrust) => STEER + ((*thrust).clamp(-MAX_THRUST, MAX_THRUST) + MAX_THRUST) as u8,
+        }
+    }
+
+    fn from_code(code: u8) -> Result<Self, ReplayError> {
+        Ok(match code {
+            0 => Input::Go(Side::Left),
+            1 => Input::Go(Side::Right),
+            2 => Input::Flip,
+            3 => Input::Shoot,
+            4 => Input::Zoom,
+            code if (STEER..=STEER + 2 * MAX_THRUST as u8).contains(&code) => Input::Steer((code - STEER) as i16 - MAX_THRUST),
+            _ => return Err(ReplayError::BadInput(code)),
+        })
+    }
+}
+
+fn side(code: u8) -> Side { if code & 1 == 0 { Side::Left } else { Side::Right } }
+
+impl Entrance {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        let code = |side: &Side| (*side == Side::Right) as u8;
+        match self {
+            Entrance::Spawn(from) => bytes.push(code(from)),
+            Entrance::Flying(from, height) => { bytes.push(2 | code(from)); bytes.extend(height.to_be_bytes()) }
+            Entrance::Up => bytes.push(4),
+            Entrance::Down => bytes.push(5),
+            Entrance::Air => bytes.push(6),
+        }
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ReplayError> {
+        let (&head, rest) = self.0.split_first_chunk::<N>().ok_or(ReplayError::Truncated)?;
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn varint(&mut self) -> Result<u32, ReplayError> {
+        let mut value = 0u32;
+        for shift in (0..35).step_by(7) {
+            let [byte] = self.take()?;
+            value |= ((byte & 0x7F) as u32) << shift;
+            if byte & 0x80 == 0 { return Ok(value) }
+        }
+        Err(ReplayError::NotAReplay)
+    }
+
+    fn entrance(&mut self) -> Result<Entrance, ReplayError> {
+        let [code] = self.take()?;
+        Ok(match code {
+            0 | 1 => Entrance::Spawn(side(code)),
+            2 | 3 => Entrance::Flying(side(code), u16::from_be_bytes(self.take()?)),
+            4 => Entrance::Up,
+            5 => Entrance::Down,
+            6 => Entrance::Air,
+            _ => return Err(ReplayError::BadEntrance(code)),
+        })
+    }
+}
+
+fn varint(bytes: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+impl Replay {
+    pub fn new(seed: u64) -> Self { Self{seed, marks: Vec::new(), checksum: None} }
+
+    pub fn enter(&mut self, room: NonZero<u16>, at: Entrance) { self.marks.push(Mark::Enter(room, at)) }
+
+    pub fn frame(&mut self, inputs: &[Input]) {
+        match self.marks.last_mut() {
+            Some(Mark::Frames(count, same)) if same[..] == *inputs && *count < u32::MAX => *count += 1,
+            _ => self.marks.push(Mark::Frames(1, inputs.to_vec())),
+        }
+    }
+
+    pub fn finish(&mut self, checksum: u64) { self.checksum = Some(checksum) }
+
+    pub fn frames(&self) -> impl Iterator<Item = &[Input]> {
+        self.marks.iter().flat_map(|mark| match mark {
+            Mark::Frames(count, inputs) => Some(std::iter::repeat_n(&inputs[..], *count as usize)),
+            Mark::Enter(..) => None,
+        }).flatten()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::from(*MAGIC);
+        bytes.push(VERSION);
+        bytes.extend(self.seed.to_be_bytes());
+        bytes.push(self.checksum.is_some() as u8);
+        bytes.extend(self.checksum.unwrap_or_default().to_be_bytes());
+        for mark in &self.marks {
+            match mark {
+                Mark::Enter(room, at) => {
+                    bytes.push(ENTER);
+                    bytes.extend(room.get().to_be_bytes());
+                    at.encode(&mut bytes);
+                }
+                Mark::Frames(count, inputs) => {
+                    let inputs = &inputs[..inputs.len().min(ENTER as usize - 1)];
+                    bytes.push(inputs.len() as u8);
+                    bytes.extend(inputs.iter().map(Input::code));
+                    varint(&mut bytes, *count);
+                }
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let mut reader = Reader(bytes);
+        if reader.take::<4>().ok().as_ref() != Some(MAGIC) { return Err(ReplayError::NotAReplay) }
+        let [version] = reader.take()?;
+        if version != VERSION { return Err(ReplayError::Version(version)) }
+        let seed = u64::from_be_bytes(reader.take()?);
+        let [finished] = reader.take()?;
+        let checksum = u64::from_be_bytes(reader.take()?);
+        let checksum = match finished {
+            0 => None,
+            1 => Some(checksum),
+            _ => return Err(ReplayError::NotAReplay),
+        };
+        let mut marks = Vec::new();
+        while let Ok([tag]) = reader.take() {
+            marks.push(if tag == ENTER {
+                let room = NonZero::new(u16::from_be_bytes(reader.take()?)).ok_or(ReplayError::NotAReplay)?;
+                Mark::Enter(room, reader.entrance()?)
+            } else {
+                let inputs = (0..tag).map(|_| Input::from_code(reader.take::<1>()?[0])).collect::<Result<Vec<_>, _>>()?;
+                Mark::Frames(reader.varint()?, inputs)
+            });
+        }
+        Ok(Self{seed, marks, checksum})
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        std::fs::write(path, self.to_bytes())
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn run(&self, rooms: &[Room]) -> Result<Ending, ReplayError> {
+        let mut plays = rooms.iter().enumerate().map(|(i, room)| room.start(Entrance::default(), self.seed.wrapping_add(i as u64))).collect::<Vec<Play>>();
+        let mut status = Status::default();
+        let mut current = None;
+        for mark in &self.marks {
+            match mark {
+                Mark::Enter(room, at) => {
+                    let play = plays.get_mut(room.get() as usize - 1).ok_or(ReplayError::UnknownRoom(*room))?;
+                    if current == Some(*room) { play.reset(*at) } else { play.enter(*at) }
+                    play.charge(status.energy);
+                    play.arm(status.bands);
+                    current = Some(*room);
+                }
+                Mark::Frames(count, inputs) => {
+                    let room = current.ok_or(ReplayError::NoRoom)?;
+                    let play = &mut plays[room.get() as usize - 1];
+                    for _ in 0..*count {
+                        match play.frame(inputs) {
+                            Outcome::Continue(updates) => updates.iter().flatten().for_each(|update| status.record(update)),
+                            Outcome::Dead => { status.off_a_mortal(); }
+                            Outcome::Leave{score, ..} => status.score += score,
+                        }
+                    }
+                }
+            }
+        }
+        let room = current.ok_or(ReplayError::NoRoom)?;
+        let (player, _) = plays[room.get() as usize - 1].player();
+        Ok(Ending::new(status, room, player))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::House;
+
+    fn house() -> House {
+        House::open(Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses").join("The House")).unwrap()
+    }
+
+    fn session(seed: u64) -> Replay {
+        let mut replay = Replay::new(seed);
+        replay.enter(NonZero::new(1).unwrap(), Entrance::default());
+        for frame in 0..240u32 {
+            let inputs = match frame {
+                0..60 => vec![Input::Go(Side::Right)],
+                60 => vec![Input::Go(Side::Right), Input::Flip],
+                61..120 => vec![],
+                _ => vec![Input::Go(Side::Left)],
+            };
+            replay.frame(&inputs);
+        }
+        replay
+    }
+
+    #[test]
+    fn record_compactly() {
+        let replay = session(99);
+        assert_eq!(replay.marks.len(), 5);
+        assert_eq!(replay.frames().count(), 240);
+        let bytes = replay.to_bytes();
+        assert!(bytes.len() < 64);
+        assert_eq!(Replay::from_bytes(&bytes).unwrap(), replay);
+        assert!(matches!(Replay::from_bytes(b"GLRQ"), Err(ReplayError::NotAReplay)));
+        assert!(matches!(Replay::from_bytes(&bytes[..bytes.len() - 1]), Err(ReplayError::Truncated)));
+        let mut garbled = bytes.clone();
+        garbled[13] = 2;
+        assert!(matches!(Replay::from_bytes(&garbled), Err(ReplayError::NotAReplay)));
+
+        let mut steered = Replay::new(1);
+        (-MAX_THRUST - 1..=MAX_THRUST).for_each(|thrust| steered.frame(&[Input::Steer(thrust), Input::Flip]));
+        let marks = Replay::from_bytes(&steered.to_bytes()).unwrap().marks;
+        assert_eq!(marks.first(), Some(&Mark::Frames(1, vec![Input::Steer(-MAX_THRUST), Input::Flip])));
+        assert_eq!(marks[1..], steered.marks[1..]);
+    }
+
+    #[test]
+    fn replay_checksum() {
+        let house = house();
+        let mut replay = session(5);
+        let ending = replay.run(&house).unwrap();
+        replay.finish(ending.checksum);
+        let reloaded = Replay::from_bytes(&replay.to_bytes()).unwrap();
+        assert_eq!(reloaded.checksum, Some(ending.checksum));
+        assert_eq!(reloaded.run(&house).unwrap(), ending);
+        replay.frame(&[Input::Go(Side::Right)]);
+        assert_ne!(replay.run(&house).unwrap().checksum, ending.checksum);
+    }
+}
@@ -34,4 +34,6 @@ pub mod color {
 pub const CIRCLE: &'static [u8] = include_bytes!("circle.raw");
 
 pub const THE_HOUSE: &'static [u8] = include_bytes!("The House");
-pub const TWO_HOUSE: &'static [u8] = include_bytes!("The House 2");
\ No newline at end of file
+pub const TWO_HOUSE: &'static [u8] = include_bytes!("The House 2");
+pub const BW_ART: &[u8] = include_bytes!("../../Glider_405/B&W Art.bin");
+pub const SOUNDS: &'static [u8] = include_bytes!("../../Glider_Sound_Files/snd.r");
\ No newline at end of file
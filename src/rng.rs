@@ -0,0 +1,44 @@
+use random::Source;
+
+#[derive(Clone, Copy)]
+pub struct Rng {
+    seed: u64,
+    source: random::Default,
+}
+
+impl std::fmt::Debug for Rng {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Rng").field("seed", &self.seed).finish_non_exhaustive()
+    }
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self { Self{seed, source: random::default(seed)} }
+
+    pub fn from_clock() -> Self {
+        Self::new(match std::time::SystemTime::UNIX_EPOCH.elapsed() {
+            Ok(length) => length,
+            Err(wrong) => wrong.duration(),
+        }.as_secs())
+    }
+
+    pub fn seed(&self) -> u64 { self.seed }
+
+    pub fn below(&mut self, range: u16) -> i16 { (self.source.read_u64() % range.max(1) as u64) as i16 }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn repeat_from_seed() {
+        let (mut one, mut two) = (Rng::new(1234), Rng::new(1234));
+        let rolls = (0..64).map(|_| one.below(400)).collect::<Vec<_>>();
+        assert_eq!(rolls, (0..64).map(|_| two.below(400)).collect::<Vec<_>>());
+        assert!(rolls.iter().all(|roll| (0..400).contains(roll)));
+        let mut other = Rng::new(4321);
+        assert_ne!(rolls, (0..64).map(|_| other.below(400)).collect::<Vec<_>>());
+        assert_eq!(one.seed(), 1234);
+    }
+}
@@ -10,6 +10,8 @@ pub const VERT_FLOOR:		i16 = 325;
 pub const BOUNDS:	Bounds = const{ Bounds::new(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT).unwrap() };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Id(pub(crate) NonZero<u16>);
 
@@ -42,6 +44,7 @@ impl AddAssign<u16> for Id {
 
 #[disclose]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct On {
     air: bool,
     lights: bool,
@@ -49,6 +52,7 @@ pub(crate) struct On {
 
 #[disclose]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Exits {
     left: Option<Id>,
     right: Option<Id>,
@@ -74,6 +78,7 @@ impl Exits {
 
 #[disclose]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Room {
     name: String,
     back_pict_id: u16,
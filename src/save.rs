@@ -0,0 +1,219 @@
+use std::{fmt::Display, num::NonZero, path::Path};
+
+use super::{Room, Status};
+
+const MAGIC: &[u8; 4] = b"GLSV";
+const VERSION: u8 = 2;
+const LIT: u8 = 1;
+const HEATED: u8 = 2;
+
+#[disclose]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Changes {
+    lit: bool,
+    heated: bool,
+    taken: Vec<u16>,
+    toggled: Vec<u16>,
+    spilled: Vec<u16>,
+}
+
+#[disclose]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Save {
+    house: u32,
+    seed: u64,
+    room: NonZero<u16>,
+    status: Status,
+    rooms: Vec<Changes>,
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    NotASave,
+    Version(u8),
+    Truncated,
+    WrongHouse{house: u32, expected: u32},
+    UnknownRoom(NonZero<u16>),
+    Unreadable(std::io::Error),
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotASave => write!(f, "file isn't a glider saved game"),
+            Self::Version(version) => write!(f, "saved game version {version} isn't supported; expected {VERSION}"),
+            Self::Truncated => write!(f, "saved game ends early"),
+            Self::WrongHouse{house, expected} => write!(f, "saved game is of house {house:08x}, not this one ({expected:08x})"),
+            Self::UnknownRoom(id) => write!(f, "saved game stands in room {id}, which isn't in this house"),
+            Self::Unreadable(err) => write!(f, "saved game could not be read ({err})"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<std::io::Error> for SaveError {
+    fn from(value: std::io::Error) -> Self { Self::Unreadable(value) }
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool { *self == Self::default() }
+
+    pub(crate) fn toggle(&mut self, id: u16) {
+        match self.toggled.iter().position(|&toggled| toggled == id) {
+            Some(again) => { self.toggled.swap_remove(again); }
+            None => self.toggled.push(id),
+        }
+    }
+}
+
+// The rooms are hashed as they're stored, so a house still matches its saves after its scores or time stamp change.
+pub fn fingerprint(rooms: &[Room]) -> u32 {
+    rooms.iter()
+        .flat_map(|room| Vec::<u8>::try_from(room).unwrap_or_else(|_| room.name.bytes().collect()))
+        .fold(0x811C_9DC5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x0100_0193))
+}
+
+fn take<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N], SaveError> {
+    let (&head, rest) = bytes.split_first_chunk::<N>().ok_or(SaveError::Truncated)?;
+    *bytes = rest;
+    Ok(head)
+}
+
+fn ids(bytes: &mut &[u8]) -> Result<Vec<u16>, SaveError> {
+    let count = u16::from_be_bytes(take(bytes)?);
+    (0..count).map(|_| Ok(u16::from_be_bytes(take(bytes)?))).collect()
+}
+
+impl Save {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::from(*MAGIC);
+        bytes.push(VERSION);
+        bytes.extend(self.house.to_be_bytes());
+        bytes.extend(self.seed.to_be_bytes());
+        bytes.extend(self.room.get().to_be_bytes());
+        bytes.extend(self.status.score.to_be_bytes());
+        [self.status.mortals, self.status.energy, self.status.bands].into_iter().for_each(|n| bytes.extend(n.to_be_bytes()));
+        bytes.extend((self.rooms.len() as u16).to_be_bytes());
+        for changes in &self.rooms {
+            bytes.push(if changes.lit {LIT} else {0} | if changes.heated {HEATED} else {0});
+            for ids in [&changes.taken, &changes.toggled, &changes.spilled] {
+                bytes.extend((ids.len() as u16).to_be_bytes());
+                ids.iter().for_each(|id| bytes.extend(id.to_be_bytes()));
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, SaveError> {
+        let bytes = &mut bytes;
+        if take::<4>(bytes).ok().as_ref() != Some(MAGIC) { return Err(SaveError::NotASave) }
+        let [version] = take(bytes)?;
+        if version != VERSION { return Err(SaveError::Version(version)) }
+        let house = u32::from_be_bytes(take(bytes)?);
+        let seed = u64::from_be_bytes(take(bytes)?);
+        let room = NonZero::new(u16::from_be_bytes(take(bytes)?)).ok_or(SaveError::NotASave)?;
+        let score = u32::from_be_bytes(take(bytes)?);
+        let mut counter = || Ok::<_, SaveError>(u16::from_be_bytes(take(bytes)?));
+        let (mortals, energy, bands) = (counter()?, counter()?, counter()?);
+        let count = counter()?;
+        let rooms = (0..count).map(|_| {
+            let [flags] = take(bytes)?;
+            Ok(Changes{lit: flags & LIT != 0, heated: flags & HEATED != 0, taken: ids(bytes)?, toggled: ids(bytes)?, spilled: ids(bytes)?})
+        }).collect::<Result<Vec<_>, SaveError>>()?;
+        if !bytes.is_empty() { return Err(SaveError::NotASave) }
+        Ok(Self{house, seed, room, status: Status{score, mortals, energy, bands}, rooms})
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        std::fs::write(path, self.to_bytes())
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SaveError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn fits(&self, house: u32, rooms: usize) -> Result<(), SaveError> {
+        if self.house != house || self.rooms.len() != rooms { return Err(SaveError::WrongHouse{house: self.house, expected: house}) }
+        if self.room.get() as usize > rooms { return Err(SaveError::UnknownRoom(self.room)) }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Entrance, Input, Object, Outcome, Position, Room, Side, Update, object::Kind, room::{Exits, On}};
+
+    fn parlor() -> Room {
+        Room {
+            name: "Parlor".into(),
+            back_pict_id: 200,
+            tile_order: [0; 8],
+            exits: Exits{left: None, right: None},
+            animate: None,
+            environs: On{air: true, lights: false},
+            objects: vec![
+                Object{kind: Kind::Clock(500), position: Position::new(60, 100)},
+                Object{kind: Kind::Fan{faces: Side::Left, range: 100, ready: true}, position: Position::new(400, 300)},
+                Object{kind: Kind::Lights, position: Position::new(124, 160)},
+            ],
+        }
+    }
+
+    #[test]
+    fn keep_what_changed() {
+        let room = parlor();
+        let mut play = room.start(Entrance::Flying(Side::Left, 100), 3);
+        let mut updates = Vec::new();
+        for _ in 0..30 {
+            let Outcome::Continue(frame) = play.frame(&[Input::Go(Side::Right)]) else { panic!("glider lost") };
+            updates.extend(frame.unwrap_or_default());
+        }
+        assert!(updates.iter().any(|update| matches!(update, Update::Score(500, _))));
+        assert!(updates.iter().any(|update| matches!(update, Update::Lights)));
+        assert_eq!(play.changes(), &Changes{lit: true, taken: vec![1], ..Changes::default()});
+
+        let mut changes = play.changes().clone();
+        changes.toggle(2);
+        changes.toggle(2);
+        assert!(changes.toggled.is_empty());
+        changes.toggle(2);
+        let mut fresh = room.start(Entrance::default(), 3);
+        fresh.restore(&changes);
+        assert!(!fresh.dark());
+        assert!(fresh.enumerate().all(|(_, object)| !matches!(object.kind, Kind::Clock(..))));
+        assert!(fresh.enumerate().any(|(_, object)| matches!(object.kind, Kind::Fan{ready: false, ..})));
+        assert_eq!(fresh.changes(), &changes);
+    }
+
+    #[test]
+    fn save_round_trip() {
+        let house = [parlor()];
+        let save = Save{
+            house: fingerprint(&house),
+            seed: 42,
+            room: NonZero::new(3).unwrap(),
+            status: Status{score: 1250, mortals: 2, energy: 40, bands: 7},
+            rooms: vec![Changes::default(), Changes{lit: true, heated: false, taken: vec![4, 9], toggled: vec![2], spilled: vec![11]}, Changes::default()],
+        };
+        let bytes = save.to_bytes();
+        assert_eq!(Save::from_bytes(&bytes).unwrap(), save);
+        assert!(matches!(Save::from_bytes(b"GLRP"), Err(SaveError::NotASave)));
+        assert!(matches!(Save::from_bytes(&bytes[..bytes.len() - 1]), Err(SaveError::Truncated)));
+        assert!(save.fits(fingerprint(&house), 3).is_ok());
+        assert!(matches!(save.fits(fingerprint(&house), 2), Err(SaveError::WrongHouse{..})));
+    }
+
+    #[test]
+    fn refuse_another_house() {
+        let mut house = vec![parlor(), parlor()];
+        let save = Save{house: fingerprint(&house), seed: 7, room: NonZero::new(2).unwrap(), status: Status::default(), rooms: vec![Changes::default(); 2]};
+        assert!(save.fits(fingerprint(&house), 2).is_ok());
+        house[1].objects[0].position = Position::new(80, 100);
+        let moved = fingerprint(&house);
+        assert!(matches!(save.fits(moved, 2), Err(SaveError::WrongHouse{house, expected}) if house == save.house && expected == moved));
+        house[1].name = "Attic".into();
+        assert_ne!(fingerprint(&house), moved);
+    }
+}
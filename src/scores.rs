@@ -0,0 +1,51 @@
+use std::{error::Error, time::{Duration, Instant}};
+
+use glider::Success;
+use sdl2::{event::{Event, WindowEvent}, keyboard::Scancode, pixels::Color};
+use crate::draw::{Banner, Board, Scribe};
+
+const NAME_LENGTH: usize = 25;
+const BOARD_TIME: Duration = Duration::from_secs(15);
+
+impl crate::App {
+    pub fn enter_name(&mut self, score: u32) -> Result<Option<String>, Box<dyn Error>> {
+        let creator = self.display.texture_creator();
+        let sprites = self.sprites().over(self.sprites().as_ref().as_texture(&creator)?);
+        let typing = self.display.window().subsystem().text_input();
+        let mut display = (&mut self.display, &sprites);
+        let title = format!("NEW HIGH SCORE {score}");
+        let mut name = String::new();
+        typing.start();
+        let entered = 'typing: loop {
+            for event in self.events.poll_iter() {
+                match event {
+                    Event::Quit{..} | Event::KeyDown{scancode: Some(Scancode::Escape), ..} => break 'typing None,
+                    Event::KeyDown{scancode: Some(Scancode::Return | Scancode::KpEnter), ..} => break 'typing Some(name.trim().to_string()).filter(|name| !name.is_empty()),
+                    Event::KeyDown{scancode: Some(Scancode::Backspace), ..} => { name.pop(); }
+                    Event::KeyDown{scancode: Some(crate::FULLSCREEN_KEY), repeat: false, ..} => crate::toggle_fullscreen(display.0, &mut self.prefs),
+                    Event::KeyDown{scancode: Some(crate::INTEGER_SCALE_KEY), repeat: false, ..} => crate::toggle_integer_scale(display.0, &mut self.prefs),
+                    Event::Window{win_event: WindowEvent::SizeChanged(width, height), ..} => crate::resize(&mut self.prefs, width, height),
+                    Event::TextInput{text, ..} => name.extend(text.chars().filter(|c| !c.is_control()).take(NAME_LENGTH - name.chars().count())),
+                    _ => ()
+                }
+            }
+            display.show(&Color::RGB(0, 0, 0)).show(&Banner(&title, &format!("NAME: {name}_"))).publish();
+            std::thread::sleep(Duration::from_millis(33));
+        };
+        typing.stop();
+        Ok(entered)
+    }
+
+    pub fn show_scores(&mut self, house: &str, scores: &[Success], highlight: Option<usize>) -> Result<(), Box<dyn Error>> {
+        let creator = self.display.texture_creator();
+        let sprites = self.sprites().over(self.sprites().as_ref().as_texture(&creator)?);
+        let mut display = (&mut self.display, &sprites);
+        let shown = Instant::now();
+        while shown.elapsed() < BOARD_TIME {
+            if self.events.poll_iter().any(|event| matches!(event, Event::Quit{..} | Event::KeyDown{..} | Event::MouseButtonDown{..})) { break }
+            display.show(&Color::RGB(0, 0, 0)).show(&Board(&house.to_uppercase(), scores, highlight)).publish();
+            std::thread::sleep(Duration::from_millis(33));
+        }
+        Ok(())
+    }
+}
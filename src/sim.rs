@@ -0,0 +1,61 @@
+use super::{Entrance, Input, Outcome, Room};
+
+#[disclose]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Step {
+    frame: u32,
+    player: (i16, i16),
+    outcome: Outcome,
+}
+
+pub fn simulate<'a>(room: &Room, at: Entrance, seed: u64, bands: u16, frames: u32, inputs: impl IntoIterator<Item = &'a [Input]>) -> Vec<Step> {
+    let mut play = room.start(at, seed);
+    play.arm(bands);
+    let mut inputs = inputs.into_iter();
+    let mut steps = Vec::new();
+    for frame in 0..frames {
+        let outcome = play.frame(inputs.next().unwrap_or_default());
+        let (player, _) = play.player();
+        let done = !matches!(outcome, Outcome::Continue(..));
+        steps.push(Step{frame, player, outcome});
+        if done { break }
+    }
+    steps
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{House, Side, Update};
+
+    fn first_room() -> Room {
+        House::open(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Houses").join("The House")).unwrap().rooms.swap_remove(0)
+    }
+
+    #[test]
+    fn fly_right_until_leaving() {
+        let right = [Input::Go(Side::Right)];
+        let steps = simulate(&first_room(), Entrance::default(), 1, 0, 2000, std::iter::repeat(&right[..]));
+        assert!(steps.len() < 2000);
+        assert!(steps.windows(2).all(|pair| pair[0].frame + 1 == pair[1].frame));
+        assert!(matches!(steps.last().unwrap().outcome, Outcome::Leave{..} | Outcome::Dead));
+        assert!(steps.iter().any(|step| matches!(step.outcome, Outcome::Continue(Some(ref updates)) if updates.iter().any(|update| matches!(update, Update::Fade(true))))));
+    }
+
+    #[test]
+    fn idle_without_inputs() {
+        let steps = simulate(&first_room(), Entrance::default(), 1, 0, 10, []);
+        assert_eq!(steps.len(), 10);
+        assert!(steps.iter().all(|step| matches!(step.outcome, Outcome::Continue(..))));
+    }
+
+    #[test]
+    fn shoot_only_with_bands() {
+        let shoot = [Input::Shoot];
+        let shots = |bands| simulate(&first_room(), Entrance::default(), 1, bands, 60, std::iter::repeat(&shoot[..])).iter()
+            .filter(|step| matches!(step.outcome, Outcome::Continue(Some(ref updates)) if updates.iter().any(|update| matches!(update, Update::Shoot))))
+            .count();
+        assert_eq!((shots(0), shots(1)), (0, 1));
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt::Display;
+
+use super::{Environment, Update};
+
+const BUFFER_COMMAND: u16 = 0x51;
+const SOUND_COMMAND: u16 = 0x50;
+const HEADER_SIZE: usize = 22;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cue {
+    Awww = 1,
+    BeamIn,
+    BlowerOn,
+    Bounce,
+    Clock,
+    Crunch,
+    Drip,
+    Yeow,
+    Energize,
+    Extra,
+    FireBand,
+    GetBand,
+    Whistle,
+    GreaseSpill,
+    Strum,
+    Hey,
+    Thunder,
+    Click,
+    Pop,
+    Shred,
+    ScoreTick,
+    ToastLand,
+    ToastLaunch,
+    Zap,
+    TeapotWhistle,
+    LittleTick,
+}
+
+impl Cue {
+    pub const fn resource(self) -> i16 { 1000 + self as i16 }
+}
+
+impl Update {
+    pub fn cue(&self) -> Option<Cue> {
+        Some(match self {
+            Update::Score(..) => Cue::Clock,
+            Update::Life(..) => Cue::Extra,
+            Update::Bands(..) => Cue::GetBand,
+            Update::Energy(_, Some(_)) => Cue::Energize,
+            Update::Zoom => Cue::LittleTick,
+            Update::Shoot => Cue::FireBand,
+            Update::Pop(..) => Cue::Pop,
+            Update::Start(Environment::Guitar, ..) => Cue::Strum,
+            Update::Start(Environment::Grease, ..) => Cue::GreaseSpill,
+            Update::Start(Environment::Outlet, ..) => Cue::Zap,
+            Update::Start(Environment::Switch, ..) => Cue::Click,
+            Update::Start(Environment::Ball, ..) => Cue::Bounce,
+            Update::Start(Environment::Drip, ..) => Cue::Drip,
+            Update::Start(Environment::Toast, ..) => Cue::ToastLaunch,
+            Update::Start(Environment::Duct, ..) => Cue::Hey,
+            Update::Bump => Cue::ScoreTick,
+            Update::Fade(true) => Cue::BeamIn,
+            Update::Fade(false) => Cue::Awww,
+            Update::Burn => Cue::Yeow,
+            Update::Lights => Cue::Click,
+            Update::Air => Cue::BlowerOn,
+            Update::Energy(_, None) | Update::Start(Environment::Fish, ..) | Update::Turn(..) => return None,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SoundError {
+    Truncated(usize),
+    UnsupportedFormat(u16),
+    UnsupportedEncoding(u8),
+    NoSampledSound,
+    BadHex{line: usize},
+}
+
+impl Display for SoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated(size) => write!(f, "sound resource ends early; needed {size} bytes"),
+            Self::UnsupportedFormat(format) => write!(f, "'snd ' format {format} isn't supported; expected format 1"),
+            Self::UnsupportedEncoding(encode) => write!(f, "sampled sound encoding {encode} isn't supported; expected 8-bit standard"),
+            Self::NoSampledSound => write!(f, "sound resource has no buffer command pointing to sampled sound"),
+            Self::BadHex{line} => write!(f, "line {line} holds malformed hex data"),
+        }
+    }
+}
+
+impl std::error::Error for SoundError {}
+
+#[disclose]
+#[derive(Debug, Clone)]
+pub struct Sample {
+    rate: f64,
+    data: Vec<u8>,
+}
+
+fn bytes<const N: usize>(resource: &[u8], at: usize) -> Result<[u8; N], SoundError> {
+    resource.get(at..at + N).and_then(|slice| slice.try_into().ok()).ok_or(SoundError::Truncated(at + N))
+}
+
+impl Sample {
+    pub fn decode(resource: &[u8]) -> Result<Self, SoundError> {
+        let format = u16::from_be_bytes(bytes(resource, 0)?);
+        if format != 1 { return Err(SoundError::UnsupportedFormat(format)) }
+        let modifiers = u16::from_be_bytes(bytes(resource, 2)?) as usize;
+        let commands_at = 4 + modifiers * 6;
+        let commands = u16::from_be_bytes(bytes(resource, commands_at)?) as usize;
+        let header = (0..commands)
+            .map(|n| commands_at + 2 + n * 8)
+            .map(|at| Ok((u16::from_be_bytes(bytes(resource, at)?) & 0x7FFF, u32::from_be_bytes(bytes(resource, at + 4)?) as usize)))
+            .collect::<Result<Vec<_>, SoundError>>()?
+            .into_iter()
+            .find_map(|(command, offset)| matches!(command, BUFFER_COMMAND | SOUND_COMMAND).then_some(offset))
+            .ok_or(SoundError::NoSampledSound)?;
+        let length = u32::from_be_bytes(bytes(resource, header + 4)?) as usize;
+        let rate = u32::from_be_bytes(bytes(resource, header + 8)?) as f64 / 65536.0;
+        let [encode] = bytes(resource, header + 20)?;
+        if encode != 0 { return Err(SoundError::UnsupportedEncoding(encode)) }
+        let start = header + HEADER_SIZE;
+        let data = resource.get(start..start + length).ok_or(SoundError::Truncated(start + length))?;
+        Ok(Self{rate, data: data.to_vec()})
+    }
+
+    pub fn level(&self, at: usize) -> Option<f32> {
+        self.data.get(at).map(|&offset| (offset as f32 - 128.0) / 128.0)
+    }
+
+    pub fn pcm(&self) -> impl Iterator<Item = f32> + '_ {
+        (0..self.data.len()).filter_map(|at| self.level(at))
+    }
+
+    pub fn seconds(&self) -> f64 { self.data.len() as f64 / self.rate }
+}
+
+pub fn rez_resources(text: &[u8], kind: &str) -> Result<Vec<(i16, String, Vec<u8>)>, SoundError> {
+    let opening = format!("data '{kind}' (");
+    let mut found = Vec::new();
+    let mut current: Option<(i16, String, Vec<u8>)> = None;
+    for (n, line) in text.split(|&b| b == b'\n').enumerate() {
+        let line = String::from_utf8_lossy(line);
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix(&opening) {
+            let (id, rest) = rest.split_once(',').unwrap_or((rest.trim_end_matches(") {"), ""));
+            let name = rest.split('"').nth(1).unwrap_or_default().to_string();
+            current = id.trim().parse().ok().map(|id| (id, name, Vec::new()));
+        } else if line.starts_with("};") {
+            found.extend(current.take());
+        } else if let (Some((.., data)), Some(hex)) = (&mut current, line.strip_prefix("$\"")) {
+            let digits = hex.split('"').next().unwrap_or_default().split_whitespace().collect::<String>();
+            if digits.len() % 2 != 0 { return Err(SoundError::BadHex{line: n + 1}) }
+            for pair in digits.as_bytes().chunks(2) {
+                let pair = std::str::from_utf8(pair).map_err(|_| SoundError::BadHex{line: n + 1})?;
+                data.push(u8::from_str_radix(pair, 16).map_err(|_| SoundError::BadHex{line: n + 1})?);
+            }
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn library() -> Vec<(i16, String, Vec<u8>)> {
+        let text = std::fs::read(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Glider_Sound_Files").join("snd.r")).unwrap();
+        rez_resources(&text, "snd ").unwrap()
+    }
+
+    #[test]
+    fn decode_stock_sounds() {
+        let sounds = library();
+        assert_eq!(sounds.len(), 30);
+        let (_, name, resource) = sounds.iter().find(|(id, ..)| *id == Cue::Awww.resource()).unwrap();
+        assert_eq!(name, "Awww");
+        let awww = Sample::decode(resource).unwrap();
+        assert_eq!(awww.data.len(), 0x43AC);
+        assert_eq!(awww.rate, 22257.0);
+        assert_eq!(awww.data[..3], [0xB4, 0xBD, 0xC0]);
+        assert!(awww.pcm().all(|level| (-1.0..1.0).contains(&level)));
+        for (id, name, resource) in &sounds {
+            let sample = Sample::decode(resource).unwrap_or_else(|e| panic!("{id} {name}: {e}"));
+            assert!(sample.seconds() > 0.0 && sample.seconds() < 10.0, "{name}");
+        }
+        assert!((Cue::Awww as i16..=Cue::LittleTick as i16).all(|n| sounds.iter().any(|(id, ..)| *id == 1000 + n)));
+    }
+
+    #[test]
+    fn reject_unsupported_sounds() {
+        assert_eq!(Sample::decode(&[0, 2, 0, 0]).unwrap_err(), SoundError::UnsupportedFormat(2));
+        assert_eq!(Sample::decode(&[0, 1, 0, 0]).unwrap_err(), SoundError::Truncated(6));
+        assert_eq!(Sample::decode(&[0, 1, 0, 0, 0, 1, 0x80, 0x2B, 0, 0, 0, 0, 0, 0]).unwrap_err(), SoundError::NoSampledSound);
+        let header = [0, 1, 0, 0, 0, 1, 0x80, 0x51, 0, 0, 0, 0, 0, 14];
+        let mut sound = [&header[..], &[0, 0, 0, 0, 0, 0, 0, 4, 0x2B, 0x11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x3C]].concat();
+        assert_eq!(Sample::decode(&sound).unwrap_err(), SoundError::Truncated(40));
+        sound.extend([0x80, 0xFF, 0x00, 0x80]);
+        let sample = Sample::decode(&sound).unwrap();
+        assert_eq!((sample.rate, sample.pcm().collect::<Vec<_>>()), (11025.0, vec![0.0, 127.0 / 128.0, -1.0, 0.0]));
+        sound[14 + 20] = 0xFE;
+        assert_eq!(Sample::decode(&sound).unwrap_err(), SoundError::UnsupportedEncoding(0xFE));
+    }
+}
@@ -0,0 +1,100 @@
+use super::{Input, Update};
+
+pub const STARTING_MORTALS: u16 = 5;
+
+#[disclose]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Status {
+    score: u32,
+    mortals: u16,
+    energy: u16,
+    bands: u16,
+}
+
+impl Default for Status {
+    fn default() -> Self { Self{score: 0, mortals: STARTING_MORTALS, energy: 0, bands: 0} }
+}
+
+impl Status {
+    pub fn record(&mut self, update: &Update) {
+        match *update {
+            Update::Score(points, _) => self.score += points as u32,
+            Update::Life(points, _) => {
+                self.score += points as u32;
+                self.mortals = self.mortals.saturating_add(1);
+            }
+            Update::Energy(remaining, _) => self.energy = remaining,
+            Update::Bands(count, _) => self.bands = self.bands.saturating_add(count as u16),
+            Update::Shoot => self.bands = self.bands.saturating_sub(1),
+            _ => (),
+        }
+    }
+
+    pub fn permits(&self, input: &Input) -> bool {
+        match input {
+            Input::Shoot => self.bands > 0,
+            Input::Zoom => self.energy > 0,
+            _ => true,
+        }
+    }
+
+    pub fn off_a_mortal(&mut self) -> bool {
+        self.mortals = self.mortals.saturating_sub(1);
+        !self.is_over()
+    }
+
+    pub fn is_over(&self) -> bool { self.mortals == 0 }
+
+    pub fn spares(&self) -> u16 { self.mortals.saturating_sub(1) }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Position;
+
+    #[test]
+    fn collect_prizes() {
+        let mut status = Status::default();
+        let at = Position::new(100, 100);
+        [Update::Score(500, at), Update::Energy(25, Some(at)), Update::Bands(10, at), Update::Bands(5, at), Update::Bump]
+            .iter().for_each(|update| status.record(update));
+        assert_eq!(status, Status{score: 500, mortals: STARTING_MORTALS, energy: 25, bands: 15});
+        status.record(&Update::Life(250, at));
+        assert_eq!((status.score, status.mortals, status.spares()), (750, STARTING_MORTALS + 1, STARTING_MORTALS));
+    }
+
+    #[test]
+    fn spend_bands() {
+        let mut status = Status::default();
+        assert!(!status.permits(&Input::Shoot));
+        status.record(&Update::Bands(2, Position::new(100, 100)));
+        for _ in 0..2 {
+            assert!(status.permits(&Input::Shoot));
+            status.record(&Update::Shoot);
+        }
+        assert!(!status.permits(&Input::Shoot) && status.bands == 0);
+        assert!(status.permits(&Input::Flip));
+    }
+
+    #[test]
+    fn drain_energy() {
+        let mut status = Status::default();
+        assert!(!status.permits(&Input::Zoom));
+        status.record(&Update::Energy(40, Some(Position::new(100, 100))));
+        assert!(status.permits(&Input::Zoom));
+        status.record(&Update::Energy(0, None));
+        assert!(!status.permits(&Input::Zoom));
+    }
+
+    #[test]
+    fn run_out_of_gliders() {
+        let mut status = Status::default();
+        for _ in 1..STARTING_MORTALS { assert!(status.off_a_mortal()) }
+        assert_eq!(status.spares(), 0);
+        assert!(!status.off_a_mortal());
+        assert!(status.is_over());
+        assert!(!status.off_a_mortal() && status.mortals == 0);
+    }
+}